@@ -1,8 +1,11 @@
-use std::fmt;
-use std::ops;
+use core::fmt;
+use core::ops;
+
+use crate::math::round;
+use crate::Ratio;
 
 /// Construct an angle from degrees. Angles outside of the 0-359° range will be
-/// normalized accordingly.
+/// normalized accordingly. Usable in `const` contexts.
 ///
 /// # Example
 /// ```
@@ -13,7 +16,7 @@ use std::ops;
 /// assert_eq!(deg(540).to_string(), "180deg");
 /// assert_eq!(deg(-90).to_string(), "270deg");
 /// ```
-pub fn deg(mut degrees: i32) -> Angle {
+pub const fn deg(mut degrees: i32) -> Angle {
     while degrees < 0 {
         degrees += 360;
     }
@@ -25,23 +28,63 @@ pub fn deg(mut degrees: i32) -> Angle {
     Angle::new(degrees as u16)
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 /// A struct that represents the number of degrees in a circle.
 /// Legal values range from `0-359`. Anything else is unused.
+///
+/// Adding and subtracting angles wraps around the circle. Angles are normalized
+/// when they're constructed, so comparisons, including ordering, use the
+/// normalized degrees: `deg(370)` equals `deg(10)`, and `deg(-10)` is greater
+/// than `deg(10)` as it's stored as `350deg`. The default angle is `0deg`.
+///
+/// # Example
+/// ```
+/// use farver::deg;
+///
+/// assert_eq!(deg(350) + deg(30), deg(20));
+/// assert_eq!(deg(20) - deg(30), deg(350));
+/// assert_eq!(deg(370), deg(10));
+/// assert!(deg(-10) > deg(10));
+/// ```
 pub struct Angle {
     degrees: u16,
 }
 
 impl Angle {
-    pub fn new(degrees: u16) -> Self {
+    pub const fn new(degrees: u16) -> Self {
         assert!(degrees < 360, "invalid angle");
 
         Angle { degrees }
     }
 
-    pub fn degrees(self) -> u16 {
+    pub const fn degrees(self) -> u16 {
         self.degrees
     }
+
+    /// Interpolates between `self` and `other`, going whichever way round the circle is
+    /// shorter, so a `t` of 0% gives `self`, 100% gives `other`, and 50% the angle halfway
+    /// along that shorter arc. Opposite angles go round clockwise (increasing the degrees).
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{deg, percent};
+    ///
+    /// assert_eq!(deg(350).lerp_shortest(deg(30), percent(0)), deg(350));
+    /// assert_eq!(deg(350).lerp_shortest(deg(30), percent(50)), deg(10));
+    /// assert_eq!(deg(350).lerp_shortest(deg(30), percent(100)), deg(30));
+    /// assert_eq!(deg(30).lerp_shortest(deg(350), percent(50)), deg(10));
+    /// ```
+    pub fn lerp_shortest(self, other: Angle, t: Ratio) -> Angle {
+        let mut difference = other.degrees as i32 - self.degrees as i32;
+
+        if difference > 180 {
+            difference -= 360;
+        } else if difference <= -180 {
+            difference += 360;
+        }
+
+        deg(self.degrees as i32 + round(difference as f32 * t.as_f32()) as i32)
+    }
 }
 
 impl fmt::Display for Angle {
@@ -107,7 +150,8 @@ impl ops::Div for Angle {
 
 #[cfg(test)]
 mod tests {
-    use crate::Angle;
+    use crate::{deg, percent, Angle, Ratio};
+    use core::cmp::Ordering;
 
     #[test]
     fn can_have_degrees() {
@@ -115,6 +159,32 @@ mod tests {
         assert_eq!(Angle::new(47).degrees(), 47);
     }
 
+    #[test]
+    fn builds_angles_in_const_contexts() {
+        const RIGHT: Angle = deg(-270);
+
+        assert_eq!(RIGHT, Angle::new(90));
+        assert_eq!(RIGHT.degrees(), 90);
+    }
+
+    #[test]
+    fn lerps_the_shortest_way_round() {
+        let (start, half, end) = (percent(0), percent(50), Ratio::FULL);
+
+        assert_eq!(deg(10).lerp_shortest(deg(90), start), deg(10));
+        assert_eq!(deg(10).lerp_shortest(deg(90), half), deg(50));
+        assert_eq!(deg(10).lerp_shortest(deg(90), end), deg(90));
+
+        assert_eq!(deg(300).lerp_shortest(deg(20), start), deg(300));
+        assert_eq!(deg(300).lerp_shortest(deg(20), half), deg(340));
+        assert_eq!(deg(300).lerp_shortest(deg(20), end), deg(20));
+        assert_eq!(deg(20).lerp_shortest(deg(300), half), deg(340));
+
+        assert_eq!(deg(0).lerp_shortest(deg(180), half), deg(90));
+        assert_eq!(deg(180).lerp_shortest(deg(0), half), deg(270));
+        assert_eq!(deg(45).lerp_shortest(deg(45), half), deg(45));
+    }
+
     #[test]
     fn can_display_angles() {
         assert_eq!("30deg", format!("{}", Angle::new(30)));
@@ -129,21 +199,40 @@ mod tests {
 
     #[test]
     fn can_ord_angles() {
-        assert_eq!(Angle::new(30) < Angle::new(47), true);
-        assert_eq!(Angle::new(47) < Angle::new(30), false);
-        assert_eq!(Angle::new(30) < Angle::new(30), false);
+        assert!(Angle::new(30) < Angle::new(47));
+        assert!(!Angle::new(47).lt(&Angle::new(30)));
+        assert!(!Angle::new(30).lt(&Angle::new(30)));
 
-        assert_eq!(Angle::new(30) <= Angle::new(47), true);
-        assert_eq!(Angle::new(47) <= Angle::new(30), false);
-        assert_eq!(Angle::new(30) <= Angle::new(30), true);
+        assert!(Angle::new(30) <= Angle::new(47));
+        assert!(!Angle::new(47).le(&Angle::new(30)));
+        assert!(Angle::new(30) <= Angle::new(30));
 
-        assert_eq!(Angle::new(30) > Angle::new(47), false);
-        assert_eq!(Angle::new(47) > Angle::new(30), true);
-        assert_eq!(Angle::new(30) > Angle::new(30), false);
+        assert!(!Angle::new(30).gt(&Angle::new(47)));
+        assert!(Angle::new(47) > Angle::new(30));
+        assert!(!Angle::new(30).gt(&Angle::new(30)));
+
+        assert!(!Angle::new(30).ge(&Angle::new(47)));
+        assert!(Angle::new(47) >= Angle::new(30));
+        assert!(Angle::new(30) >= Angle::new(30));
+    }
+
+    #[test]
+    fn orders_normalized_degrees() {
+        assert_eq!(deg(370), deg(10));
+        assert_eq!(deg(-350), deg(10));
+        assert_eq!(deg(370).cmp(&deg(10)), Ordering::Equal);
+        assert!(deg(-10) > deg(10));
+        assert!(deg(720) < deg(1));
+    }
+
+    #[test]
+    fn wraps_hue_arithmetic() {
+        let base_hue = deg(340);
 
-        assert_eq!(Angle::new(30) >= Angle::new(47), false);
-        assert_eq!(Angle::new(47) >= Angle::new(30), true);
-        assert_eq!(Angle::new(30) >= Angle::new(30), true);
+        assert_eq!(base_hue + deg(30), deg(10));
+        assert_eq!(base_hue - deg(-30), deg(10));
+        assert_eq!(deg(10) - base_hue, deg(30));
+        assert_eq!(base_hue + deg(360), base_hue);
     }
 
     #[test]
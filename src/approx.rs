@@ -0,0 +1,122 @@
+use crate::{Angle, Ratio, HSL, HSLA, HSV, RGB, RGBA};
+
+/// Compares colors while allowing for the small rounding errors introduced by
+/// converting between color models, such as `hsl(6, 93, 71).to_rgb().to_hsl()`.
+///
+/// The `tolerance` is the largest difference allowed for each channel, in that
+/// channel's own units: `0-255` for the red, green, blue and alpha channels,
+/// degrees for the hue and percentage points for the saturation, lightness and value of
+/// `HSL` and `HSV`. Hues wrap around, so `359°` and `0°` are a single degree apart.
+///
+/// # Example
+/// ```
+/// use farver::approx::ApproximatelyEq;
+/// use farver::{hsl, rgb, Color};
+///
+/// let salmon = hsl(6, 93, 71);
+///
+/// assert!(salmon.to_rgb().to_hsl().approximately_eq(salmon, 1));
+/// assert!(rgb(250, 128, 114).approximately_eq(rgb(252, 126, 114), 2));
+/// assert!(!rgb(250, 128, 114).approximately_eq(rgb(252, 126, 114), 1));
+/// ```
+pub trait ApproximatelyEq {
+    /// Returns whether each channel of `self` is within `tolerance` of the same channel of `other`.
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool;
+}
+
+impl ApproximatelyEq for Ratio {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        self.as_u8().abs_diff(other.as_u8()) <= tolerance
+    }
+}
+
+impl ApproximatelyEq for Angle {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        let difference = self.degrees().abs_diff(other.degrees());
+
+        difference.min(360 - difference) <= tolerance as u16
+    }
+}
+
+impl ApproximatelyEq for RGB {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        self.r.approximately_eq(other.r, tolerance)
+            && self.g.approximately_eq(other.g, tolerance)
+            && self.b.approximately_eq(other.b, tolerance)
+    }
+}
+
+impl ApproximatelyEq for RGBA {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        self.r.approximately_eq(other.r, tolerance)
+            && self.g.approximately_eq(other.g, tolerance)
+            && self.b.approximately_eq(other.b, tolerance)
+            && self.a.approximately_eq(other.a, tolerance)
+    }
+}
+
+impl ApproximatelyEq for HSL {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        self.h
+            .to_angle()
+            .approximately_eq(other.h.to_angle(), tolerance)
+            && percentages_within(self.s.as_percentage(), other.s.as_percentage(), tolerance)
+            && percentages_within(self.l.as_percentage(), other.l.as_percentage(), tolerance)
+    }
+}
+
+impl ApproximatelyEq for HSLA {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        self.h
+            .to_angle()
+            .approximately_eq(other.h.to_angle(), tolerance)
+            && percentages_within(self.s.as_percentage(), other.s.as_percentage(), tolerance)
+            && percentages_within(self.l.as_percentage(), other.l.as_percentage(), tolerance)
+            && self.a.approximately_eq(other.a, tolerance)
+    }
+}
+
+impl ApproximatelyEq for HSV {
+    fn approximately_eq(self, other: Self, tolerance: u8) -> bool {
+        self.h.approximately_eq(other.h, tolerance)
+            && percentages_within(self.s.as_percentage(), other.s.as_percentage(), tolerance)
+            && percentages_within(self.v.as_percentage(), other.v.as_percentage(), tolerance)
+    }
+}
+
+// Compares two whole percentages, the units HSL and HSV channels are written in.
+fn percentages_within(lhs: u8, rhs: u8, tolerance: u8) -> bool {
+    lhs.abs_diff(rhs) <= tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApproximatelyEq;
+    use crate::{deg, hsl, hsla, rgb, rgba, Ratio};
+
+    #[test]
+    fn compares_within_tolerance() {
+        assert!(rgb(250, 128, 114).approximately_eq(rgb(250, 128, 114), 0));
+        assert!(rgb(250, 128, 114).approximately_eq(rgb(255, 123, 114), 5));
+        assert!(!rgb(250, 128, 114).approximately_eq(rgb(255, 123, 114), 4));
+        assert!(hsl(6, 93, 71).approximately_eq(hsl(8, 91, 73), 2));
+        assert!(!hsl(6, 93, 71).approximately_eq(hsl(8, 91, 74), 2));
+        assert!(Ratio::from_u8(0).approximately_eq(Ratio::from_u8(255), 255));
+    }
+
+    #[test]
+    fn wraps_hues_around() {
+        assert!(deg(359).approximately_eq(deg(0), 1));
+        assert!(deg(2).approximately_eq(deg(358), 4));
+        assert!(!deg(2).approximately_eq(deg(358), 3));
+        assert!(deg(0).approximately_eq(deg(180), 180));
+    }
+
+    #[test]
+    fn compares_alpha_within_tolerance() {
+        assert!(rgba(250, 128, 114, 0.5).approximately_eq(rgba(251, 127, 114, 0.5), 1));
+        assert!(rgba(250, 128, 114, 0.5).approximately_eq(rgba(250, 128, 114, 0.6), 25));
+        assert!(!rgba(250, 128, 114, 0.5).approximately_eq(rgba(250, 128, 114, 0.6), 24));
+        assert!(!hsla(6, 93, 71, 0.5).approximately_eq(hsla(6, 93, 71, 0.6), 24));
+    }
+}
@@ -0,0 +1,172 @@
+use super::{deg, percent, Angle, Ratio, HSLA, RGBA};
+
+/// An entry point for constructing colors fluently, one named channel at a
+/// time. Channels that aren't set fall back to a default: hue, saturation,
+/// lightness, red, green and blue default to zero, while alpha defaults to
+/// fully opaque.
+///
+/// # Example
+/// ```
+/// use farver::{deg, hsla, percent, rgba, ColorBuilder};
+///
+/// let pale_blue = ColorBuilder::hsl()
+///     .hue(deg(200))
+///     .saturation(percent(40))
+///     .lightness(percent(80))
+///     .build();
+///
+/// let translucent_red = ColorBuilder::rgb()
+///     .red(percent(100))
+///     .alpha(percent(50))
+///     .build();
+///
+/// assert_eq!(pale_blue, hsla(200, 40, 80, 1.0));
+/// assert_eq!(translucent_red, rgba(255, 0, 0, 0.5));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ColorBuilder;
+
+impl ColorBuilder {
+    /// Starts building a color in the HSL color model.
+    pub fn hsl() -> HslBuilder {
+        HslBuilder {
+            h: deg(0),
+            s: percent(0),
+            l: percent(0),
+            a: percent(100),
+        }
+    }
+
+    /// Starts building a color in the RGB color model.
+    pub fn rgb() -> RgbBuilder {
+        RgbBuilder {
+            r: percent(0),
+            g: percent(0),
+            b: percent(0),
+            a: percent(100),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A builder for `HSLA` colors, created by [`ColorBuilder::hsl`].
+pub struct HslBuilder {
+    h: Angle,
+    s: Ratio,
+    l: Ratio,
+    a: Ratio,
+}
+
+impl HslBuilder {
+    pub fn hue(self, h: Angle) -> Self {
+        HslBuilder { h, ..self }
+    }
+
+    pub fn saturation(self, s: Ratio) -> Self {
+        HslBuilder { s, ..self }
+    }
+
+    pub fn lightness(self, l: Ratio) -> Self {
+        HslBuilder { l, ..self }
+    }
+
+    pub fn alpha(self, a: Ratio) -> Self {
+        HslBuilder { a, ..self }
+    }
+
+    pub fn build(self) -> HSLA {
+        let HslBuilder { h, s, l, a } = self;
+
+        HSLA {
+            h: h.into(),
+            s: s.into(),
+            l: l.into(),
+            a,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A builder for `RGBA` colors, created by [`ColorBuilder::rgb`].
+pub struct RgbBuilder {
+    r: Ratio,
+    g: Ratio,
+    b: Ratio,
+    a: Ratio,
+}
+
+impl RgbBuilder {
+    pub fn red(self, r: Ratio) -> Self {
+        RgbBuilder { r, ..self }
+    }
+
+    pub fn green(self, g: Ratio) -> Self {
+        RgbBuilder { g, ..self }
+    }
+
+    pub fn blue(self, b: Ratio) -> Self {
+        RgbBuilder { b, ..self }
+    }
+
+    pub fn alpha(self, a: Ratio) -> Self {
+        RgbBuilder { a, ..self }
+    }
+
+    pub fn build(self) -> RGBA {
+        let RgbBuilder { r, g, b, a } = self;
+
+        RGBA { r, g, b, a }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColorBuilder;
+    use crate::{deg, hsla, percent, rgba, Ratio};
+
+    #[test]
+    fn has_defaults() {
+        assert_eq!(ColorBuilder::hsl().build(), hsla(0, 0, 0, 1.0));
+        assert_eq!(ColorBuilder::rgb().build(), rgba(0, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn can_build_hsla() {
+        let color = ColorBuilder::hsl()
+            .hue(deg(200))
+            .saturation(percent(40))
+            .lightness(percent(80))
+            .alpha(percent(100))
+            .build();
+
+        assert_eq!(color, hsla(200, 40, 80, 1.0));
+        assert_eq!(
+            ColorBuilder::hsl().lightness(percent(60)).build(),
+            hsla(0, 0, 60, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_build_rgba() {
+        let color = ColorBuilder::rgb()
+            .red(Ratio::from_u8(250))
+            .green(Ratio::from_u8(128))
+            .blue(Ratio::from_u8(114))
+            .alpha(percent(0))
+            .build();
+
+        assert_eq!(color, rgba(250, 128, 114, 0.0));
+        assert_eq!(
+            ColorBuilder::rgb().blue(percent(100)).build(),
+            rgba(0, 0, 255, 1.0)
+        );
+    }
+
+    #[test]
+    fn later_calls_override_earlier_ones() {
+        assert_eq!(
+            ColorBuilder::hsl().hue(deg(90)).hue(deg(180)).build(),
+            hsla(180, 0, 0, 1.0)
+        );
+    }
+}
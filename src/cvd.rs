@@ -0,0 +1,104 @@
+use crate::lab::{from_linear, to_linear};
+use crate::{Ratio, RGB};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// A kind of color vision deficiency, for use with [`Color::simulate_cvd`](crate::Color::simulate_cvd).
+///
+/// Each kind simulates the complete absence of one type of cone (dichromacy).
+pub enum CvdKind {
+    /// No long-wavelength (red) cones. Reds appear dark and shift towards yellow.
+    Protanopia,
+    /// No medium-wavelength (green) cones. Reds and greens are hard to tell apart.
+    Deuteranopia,
+    /// No short-wavelength (blue) cones. Blues and greens are hard to tell apart.
+    Tritanopia,
+}
+
+// Converts linear sRGB into the LMS cone response space.
+const RGB_TO_LMS: [[f32; 3]; 3] = [
+    [17.882_4, 43.516_1, 4.119_35],
+    [3.455_65, 27.155_4, 3.867_14],
+    [0.029_956_6, 0.184_309, 1.467_09],
+];
+
+// The inverse of `RGB_TO_LMS`.
+const LMS_TO_RGB: [[f32; 3]; 3] = [
+    [0.080_944_45, -0.130_504_41, 0.116_721_07],
+    [-0.010_248_534, 0.054_019_33, -0.113_614_71],
+    [-0.000_365_296_94, -0.004_121_614_7, 0.693_511_4],
+];
+
+impl CvdKind {
+    // Projects LMS cone responses onto the plane of colors a dichromat can
+    // distinguish, replacing the missing cone's response with one predicted from
+    // the other two. See "Digital video colourmaps for checking the legibility of
+    // displays by dichromats" by Viénot, Brettel and Mollon.
+    fn project(self, [l, m, s]: [f32; 3]) -> [f32; 3] {
+        match self {
+            CvdKind::Protanopia => [2.023_44 * m - 2.525_81 * s, m, s],
+            CvdKind::Deuteranopia => [l, 0.494_207 * l + 1.248_27 * s, s],
+            CvdKind::Tritanopia => [l, m, -0.395_913 * l + 0.801_109 * m],
+        }
+    }
+}
+
+// Simulates how `rgb` appears under the color vision deficiency `kind`.
+pub(crate) fn simulate(rgb: RGB, kind: CvdKind) -> RGB {
+    let linear = [
+        to_linear(rgb.r.as_f32()),
+        to_linear(rgb.g.as_f32()),
+        to_linear(rgb.b.as_f32()),
+    ];
+
+    let [r, g, b] = multiply(LMS_TO_RGB, kind.project(multiply(RGB_TO_LMS, linear)));
+    let channel = |linear: f32| Ratio::from_f32(from_linear(linear.clamp(0.0, 1.0)));
+
+    RGB {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+fn multiply(matrix: [[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simulate, CvdKind};
+    use crate::rgb;
+
+    const KINDS: [CvdKind; 3] = [
+        CvdKind::Protanopia,
+        CvdKind::Deuteranopia,
+        CvdKind::Tritanopia,
+    ];
+
+    #[test]
+    fn shifts_red_towards_dark_yellow_for_protanopia() {
+        let red = simulate(rgb(255, 0, 0), CvdKind::Protanopia);
+
+        assert!(red.r.as_u8() < 128);
+        assert!(red.r.as_u8().abs_diff(red.g.as_u8()) <= 2);
+        assert!(red.b.as_u8() < red.g.as_u8() / 2);
+    }
+
+    #[test]
+    fn keeps_greys() {
+        for kind in KINDS {
+            assert_eq!(simulate(rgb(0, 0, 0), kind), rgb(0, 0, 0));
+            assert_eq!(simulate(rgb(255, 255, 255), kind), rgb(255, 255, 255));
+            assert!(simulate(rgb(128, 128, 128), kind).r.as_u8().abs_diff(128) <= 1);
+        }
+    }
+
+    #[test]
+    fn confuses_red_and_green_for_deuteranopia() {
+        let red = simulate(rgb(200, 60, 40), CvdKind::Deuteranopia);
+        let green = simulate(rgb(110, 120, 40), CvdKind::Deuteranopia);
+
+        assert!(red.r.as_u8().abs_diff(green.r.as_u8()) < 20);
+        assert!(red.g.as_u8().abs_diff(green.g.as_u8()) < 20);
+    }
+}
@@ -0,0 +1,203 @@
+use core::fmt;
+use core::ops;
+
+use crate::math::{rem_euclid, round};
+use crate::{deg, Angle, Ratio};
+
+// The hue of an `HSL` color, in hundredths of a degree within `0-35999`. Whole
+// degrees land exactly on a step, and the steps are fine enough for every `RGB`
+// color to survive a round trip through `HSL`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub(crate) struct FineAngle(u16);
+
+impl FineAngle {
+    const STEPS: u32 = 36000;
+
+    pub(crate) const fn from_angle(angle: Angle) -> Self {
+        FineAngle(angle.degrees() * 100)
+    }
+
+    // Wraps `degrees` around the color wheel. Infinite and `NaN` hues become 0°.
+    pub(crate) fn from_f32(degrees: f32) -> Self {
+        if !degrees.is_finite() {
+            return FineAngle(0);
+        }
+
+        let steps = round(rem_euclid(degrees, 360.0) * 100.0) as u32;
+
+        FineAngle((steps % Self::STEPS) as u16)
+    }
+
+    pub(crate) fn as_f32(self) -> f32 {
+        self.0 as f32 / 100.0
+    }
+
+    // Returns the hue rounded to whole degrees, within `0-359`.
+    pub(crate) fn degrees(self) -> u16 {
+        ((self.0 as u32 + 50) / 100 % 360) as u16
+    }
+
+    pub(crate) fn to_angle(self) -> Angle {
+        deg(self.degrees() as i32)
+    }
+}
+
+impl From<Angle> for FineAngle {
+    fn from(angle: Angle) -> Self {
+        FineAngle::from_angle(angle)
+    }
+}
+
+impl ops::Add<Angle> for FineAngle {
+    type Output = FineAngle;
+
+    fn add(self, other: Angle) -> FineAngle {
+        let steps = self.0 as u32 + FineAngle::from(other).0 as u32;
+
+        FineAngle((steps % Self::STEPS) as u16)
+    }
+}
+
+// The saturation or lightness of an `HSL` color, where `u16::MAX` is 100%. Each
+// step of a `Ratio` is exactly 257 of these, so ratios convert without loss.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub(crate) struct FineRatio(u16);
+
+impl FineRatio {
+    pub(crate) const ZERO: FineRatio = FineRatio(0);
+
+    pub(crate) const FULL: FineRatio = FineRatio(u16::MAX);
+
+    // Converts a whole percentage exactly, rather than through the nearest `Ratio`, so
+    // that `hsl(0, 100, 50)` is pure red. Like `Ratio::from_percentage`, percentages
+    // above 100% fail a debug assertion, and are clamped to 100% in release builds.
+    pub(crate) const fn from_percentage(percentage: u8) -> Self {
+        debug_assert!(percentage <= 100, "Invalid value for percentage");
+
+        let percentage = if percentage > 100 { 100 } else { percentage };
+
+        // Rounds half up, in integers so that it works in `const` contexts.
+        FineRatio(((percentage as u32 * u16::MAX as u32 + 50) / 100) as u16)
+    }
+
+    // Clamps `fraction` into `0.0-1.0`, where `NaN` becomes 0%.
+    pub(crate) fn from_f32(fraction: f32) -> Self {
+        if fraction > 1.0 {
+            FineRatio::FULL
+        } else if fraction >= 0.0 {
+            FineRatio(round(fraction * u16::MAX as f32) as u16)
+        } else {
+            FineRatio::ZERO
+        }
+    }
+
+    pub(crate) fn as_f32(self) -> f32 {
+        self.0 as f32 / u16::MAX as f32
+    }
+
+    // Rounds to a whole percentage, halves up, matching `Ratio::as_percentage`.
+    pub(crate) fn as_percentage(self) -> u8 {
+        ((self.0 as u32 * 200 + u16::MAX as u32) / (2 * u16::MAX as u32)) as u8
+    }
+
+    // Rounds to the nearest `Ratio`.
+    pub(crate) fn to_ratio(self) -> Ratio {
+        Ratio::from_u8(((self.0 as u32 + 128) / 257) as u8)
+    }
+}
+
+impl From<Ratio> for FineRatio {
+    fn from(ratio: Ratio) -> Self {
+        FineRatio(ratio.as_u8() as u16 * 257)
+    }
+}
+
+impl fmt::Display for FineRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}%", self.as_percentage())
+    }
+}
+
+// Like the arithmetic on `Ratio`, these saturate at 0% and 100%.
+impl<T: Into<FineRatio>> ops::Add<T> for FineRatio {
+    type Output = FineRatio;
+
+    fn add(self, other: T) -> FineRatio {
+        FineRatio::from_f32(self.as_f32() + other.into().as_f32())
+    }
+}
+
+impl<T: Into<FineRatio>> ops::Sub<T> for FineRatio {
+    type Output = FineRatio;
+
+    fn sub(self, other: T) -> FineRatio {
+        FineRatio::from_f32(self.as_f32() - other.into().as_f32())
+    }
+}
+
+impl<T: Into<FineRatio>> ops::Mul<T> for FineRatio {
+    type Output = FineRatio;
+
+    fn mul(self, other: T) -> FineRatio {
+        FineRatio::from_f32(self.as_f32() * other.into().as_f32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FineAngle, FineRatio};
+    use crate::{deg, percent, Ratio};
+
+    #[test]
+    fn converts_ratios_without_loss() {
+        for value in 0..=u8::MAX {
+            let ratio = Ratio::from_u8(value);
+
+            assert_eq!(FineRatio::from(ratio).to_ratio(), ratio);
+            assert_eq!(FineRatio::from(ratio).as_f32(), ratio.as_f32());
+            assert_eq!(
+                FineRatio::from(ratio).as_percentage(),
+                ratio.as_percentage()
+            );
+        }
+    }
+
+    #[test]
+    fn converts_percentages_exactly() {
+        assert_eq!(FineRatio::from_percentage(0), FineRatio::ZERO);
+        assert_eq!(FineRatio::from_percentage(100), FineRatio::FULL);
+        assert_eq!(FineRatio::from_percentage(50).as_percentage(), 50);
+        assert_eq!(FineRatio::from_percentage(20), FineRatio::from(percent(20)));
+
+        for percentage in 0..=100 {
+            assert_eq!(
+                FineRatio::from_percentage(percentage).as_percentage(),
+                percentage
+            );
+        }
+    }
+
+    #[test]
+    fn saturates_arithmetic() {
+        let half = FineRatio::from_percentage(50);
+
+        assert_eq!(half + percent(60), FineRatio::FULL);
+        assert_eq!(half - percent(60), FineRatio::ZERO);
+        assert_eq!(half * half, FineRatio::from_percentage(25));
+        assert_eq!(FineRatio::from_f32(f32::NAN), FineRatio::ZERO);
+    }
+
+    #[test]
+    fn wraps_angles() {
+        assert_eq!(FineAngle::from_f32(-90.0), FineAngle::from(deg(270)));
+        assert_eq!(FineAngle::from_f32(359.999), FineAngle::from(deg(0)));
+        assert_eq!(FineAngle::from_f32(f32::INFINITY), FineAngle::from(deg(0)));
+        assert_eq!(
+            FineAngle::from(deg(350)) + deg(30),
+            FineAngle::from(deg(20))
+        );
+        assert_eq!(FineAngle::from_f32(359.6).degrees(), 0);
+        assert_eq!(FineAngle::from_f32(12.49).to_angle(), deg(12));
+        assert_eq!(FineAngle::from_f32(12.5).as_f32(), 12.5);
+    }
+}
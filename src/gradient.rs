@@ -0,0 +1,275 @@
+use super::{Angle, Color, Ratio, RGBA};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// A gradient made of color stops at positions between 0% and 100%, like the color stops
+/// of a CSS [linear gradient](https://www.w3.org/TR/css-images-3/#linear-gradients).
+/// Unlike a CSS gradient, stops don't need to be spread evenly. Stops are kept sorted
+/// by position, and stops sharing a position stay in the order they were added, which
+/// allows for hard edges between two colors.
+///
+/// # Example
+/// ```
+/// use farver::{percent, rgb, rgba, Gradient};
+///
+/// let fire = Gradient::new()
+///     .stop(percent(0), rgb(255, 0, 0))
+///     .stop(percent(25), rgb(255, 255, 0))
+///     .stop(percent(100), rgb(255, 255, 255));
+///
+/// assert_eq!(fire.sample(percent(0)), rgba(255, 0, 0, 1.0));
+/// assert_eq!(fire.sample(percent(25)), rgba(255, 255, 0, 1.0));
+/// assert_eq!(fire.sample(percent(40)), rgba(255, 255, 51, 1.0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Gradient {
+    stops: Vec<(Ratio, RGBA)>,
+}
+
+impl Gradient {
+    /// Creates a gradient without any stops.
+    pub fn new() -> Self {
+        Gradient { stops: Vec::new() }
+    }
+
+    /// Adds a stop of `color` at `position`, after any stops already at the same position.
+    pub fn stop<T: Color>(mut self, position: Ratio, color: T) -> Self {
+        self.add_stop(position, color);
+        self
+    }
+
+    /// Adds a stop of `color` at `position` in place, like [`Gradient::stop`].
+    pub fn add_stop<T: Color>(&mut self, position: Ratio, color: T) {
+        let index = self.stops.partition_point(|&(p, _)| p <= position);
+
+        self.stops.insert(index, (position, color.to_rgba()));
+    }
+
+    /// Returns the stops of `self`, sorted by position.
+    pub fn stops(&self) -> &[(Ratio, RGBA)] {
+        &self.stops
+    }
+
+    /// Samples the color of `self` at `t`, linearly interpolating between the two stops
+    /// around it in the RGB color space, alpha included. Before the first stop and after
+    /// the last one, the color of the nearest stop is used. Where several stops share the
+    /// position `t`, the last of them wins. A gradient without stops is fully transparent.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{percent, rgb, rgba, Gradient};
+    ///
+    /// let flag = Gradient::new()
+    ///     .stop(percent(20), rgb(0, 0, 255))
+    ///     .stop(percent(50), rgb(0, 0, 255))
+    ///     .stop(percent(50), rgb(255, 255, 0));
+    ///
+    /// assert_eq!(flag.sample(percent(0)), rgba(0, 0, 255, 1.0));
+    /// assert_eq!(flag.sample(percent(50)), rgba(255, 255, 0, 1.0));
+    /// assert_eq!(flag.sample(percent(100)), rgba(255, 255, 0, 1.0));
+    /// ```
+    pub fn sample(&self, t: Ratio) -> RGBA {
+        self.sample_f32(t.as_f32())
+    }
+
+    /// Lazily samples `n` colors of `self` at evenly spaced positions, like
+    /// [`Gradient::sample`], from the first position (0%) to the last (100%) inclusive.
+    /// A single sample is taken at 0%. The iterator borrows `self` and doesn't allocate.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{percent, rgb, rgba, Gradient};
+    ///
+    /// let gradient = Gradient::new()
+    ///     .stop(percent(0), rgb(0, 0, 0))
+    ///     .stop(percent(100), rgb(200, 100, 0));
+    ///
+    /// let samples: Vec<_> = gradient.iter_samples(3).collect();
+    ///
+    /// assert_eq!(
+    ///     samples,
+    ///     [rgba(0, 0, 0, 1.0), rgba(100, 50, 0, 1.0), rgba(200, 100, 0, 1.0)]
+    /// );
+    /// ```
+    pub fn iter_samples(&self, n: usize) -> impl Iterator<Item = RGBA> + '_ {
+        let last = n.saturating_sub(1).max(1) as f32;
+
+        (0..n).map(move |i| self.sample_f32(i as f32 / last))
+    }
+
+    // Samples `self` at `t`, within `0.0-1.0`.
+    fn sample_f32(&self, t: f32) -> RGBA {
+        let index = self.stops.partition_point(|&(p, _)| p.as_f32() <= t);
+
+        match (index.checked_sub(1), self.stops.get(index)) {
+            (None, Some(&(_, first))) => first,
+            (Some(previous), None) => self.stops[previous].1,
+            (Some(previous), Some(&(end, to))) => {
+                let (start, from) = self.stops[previous];
+                let span = (end.as_f32() - start.as_f32()).max(f32::EPSILON);
+                let progress = (t - start.as_f32()) / span;
+
+                let channel = |from: Ratio, to: Ratio| {
+                    Ratio::from_f32(from.as_f32() + (to.as_f32() - from.as_f32()) * progress)
+                };
+
+                RGBA {
+                    r: channel(from.r, to.r),
+                    g: channel(from.g, to.g),
+                    b: channel(from.b, to.b),
+                    a: channel(from.a, to.a),
+                }
+            }
+            (None, None) => RGBA::default(),
+        }
+    }
+
+    /// Converts `self` into a CSS `linear-gradient` running towards `angle`, with a
+    /// position for every stop. Opaque stops are written as `rgb()` colors, and any
+    /// others as `rgba()` colors. CSS only accepts gradients with at least two stops.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{deg, percent, rgb, rgba, Gradient};
+    ///
+    /// let fade = Gradient::new()
+    ///     .stop(percent(0), rgb(250, 128, 114))
+    ///     .stop(percent(100), rgba(250, 128, 114, 0.5));
+    ///
+    /// assert_eq!(
+    ///     fade.to_css(deg(90)),
+    ///     "linear-gradient(90deg, rgb(250, 128, 114) 0%, rgba(250, 128, 114, 0.50) 100%)"
+    /// );
+    /// ```
+    pub fn to_css(&self, angle: Angle) -> String {
+        let mut css = format!("linear-gradient({}", angle);
+
+        for &(position, color) in &self.stops {
+            let color = if color.a == Ratio::from_u8(u8::MAX) {
+                color.to_rgb().to_css()
+            } else {
+                color.to_css()
+            };
+
+            css.push_str(&format!(", {} {}", color, position));
+        }
+
+        css.push(')');
+        css
+    }
+}
+
+impl<T: Color> FromIterator<(Ratio, T)> for Gradient {
+    fn from_iter<I: IntoIterator<Item = (Ratio, T)>>(stops: I) -> Self {
+        let mut gradient = Gradient::new();
+
+        for (position, color) in stops {
+            gradient.add_stop(position, color);
+        }
+
+        gradient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gradient;
+    use crate::{deg, percent, rgb, rgba, Ratio, RGB};
+
+    #[test]
+    fn samples_at_the_stops() {
+        let gradient = Gradient::new()
+            .stop(percent(0), rgb(255, 0, 0))
+            .stop(percent(30), rgb(0, 255, 0))
+            .stop(percent(100), rgba(0, 0, 255, 0.0));
+
+        assert_eq!(gradient.sample(percent(0)), rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.sample(percent(30)), rgba(0, 255, 0, 1.0));
+        assert_eq!(gradient.sample(percent(100)), rgba(0, 0, 255, 0.0));
+    }
+
+    #[test]
+    fn samples_between_the_stops() {
+        let gradient = Gradient::new()
+            .stop(percent(0), rgb(0, 0, 0))
+            .stop(percent(20), rgb(200, 100, 0))
+            .stop(percent(100), rgba(200, 100, 200, 0.0));
+
+        assert_eq!(gradient.sample(percent(10)), rgba(102, 51, 0, 1.0));
+        assert_eq!(
+            gradient.sample(percent(60)).to_string(),
+            "rgba(200, 100, 100, 0.50)"
+        );
+    }
+
+    #[test]
+    fn extends_the_outer_stops() {
+        let gradient = Gradient::new()
+            .stop(percent(75), RGB::WHITE)
+            .stop(percent(25), RGB::BLACK);
+
+        assert_eq!(gradient.sample(percent(0)), rgba(0, 0, 0, 1.0));
+        assert_eq!(gradient.sample(percent(50)), rgba(129, 129, 129, 1.0));
+        assert_eq!(gradient.sample(percent(100)), rgba(255, 255, 255, 1.0));
+        assert_eq!(Gradient::new().sample(percent(50)), rgba(0, 0, 0, 0.0));
+    }
+
+    #[test]
+    fn keeps_stops_at_the_same_position_in_order() {
+        let gradient: Gradient = [
+            (percent(50), RGB::RED),
+            (percent(0), RGB::BLACK),
+            (percent(50), RGB::BLUE),
+        ]
+        .into_iter()
+        .collect();
+
+        let positions: Vec<Ratio> = gradient.stops().iter().map(|&(p, _)| p).collect();
+
+        assert_eq!(positions, [percent(0), percent(50), percent(50)]);
+        assert_eq!(gradient.stops()[1].1, rgba(255, 0, 0, 1.0));
+        assert_eq!(gradient.sample(percent(49)), rgba(249, 0, 0, 1.0));
+        assert_eq!(gradient.sample(percent(50)), rgba(0, 0, 255, 1.0));
+    }
+
+    #[test]
+    fn converts_to_css() {
+        let gradient = Gradient::new()
+            .stop(percent(0), rgb(255, 0, 0))
+            .stop(percent(100), rgb(0, 0, 255));
+
+        assert_eq!(
+            gradient.to_css(deg(90)),
+            "linear-gradient(90deg, rgb(255, 0, 0) 0%, rgb(0, 0, 255) 100%)"
+        );
+        assert_eq!(
+            gradient
+                .stop(percent(40), rgba(0, 0, 0, 0.0))
+                .to_css(deg(-45)),
+            "linear-gradient(315deg, rgb(255, 0, 0) 0%, rgba(0, 0, 0, 0.00) 40%, rgb(0, 0, 255) 100%)"
+        );
+        assert_eq!(Gradient::new().to_css(deg(0)), "linear-gradient(0deg)");
+    }
+
+    #[test]
+    fn iterates_evenly_spaced_samples() {
+        let gradient = Gradient::new()
+            .stop(percent(20), rgb(255, 0, 0))
+            .stop(percent(60), rgba(0, 0, 255, 0.5));
+
+        let samples: Vec<_> = gradient.iter_samples(6).collect();
+
+        assert_eq!(samples.len(), 6);
+        assert_eq!(samples[0], gradient.stops()[0].1);
+        assert_eq!(samples[1], rgba(255, 0, 0, 1.0));
+        assert_eq!(samples[2].to_string(), "rgba(128, 0, 127, 0.75)");
+        assert_eq!(samples[5], gradient.stops()[1].1);
+
+        assert_eq!(gradient.iter_samples(0).count(), 0);
+        assert_eq!(gradient.iter_samples(1).collect::<Vec<_>>(), [samples[0]]);
+        assert_eq!(gradient.iter_samples(2).last(), Some(samples[5]));
+        assert_eq!(Gradient::new().iter_samples(3).count(), 3);
+    }
+}
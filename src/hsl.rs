@@ -1,5 +1,10 @@
-use super::{deg, percent, Angle, Color, Ratio, RGB, RGBA};
-use std::fmt;
+use super::{deg, percent, Angle, Color, Ratio, HSV, RGB, RGBA};
+use crate::fine::{FineAngle, FineRatio};
+use crate::math::{powf, rem_euclid};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Constructs a HSL Color from numerical values, similar to the
 /// [`hsl` function](css-hsl) in CSS.
@@ -7,23 +12,25 @@ use std::fmt;
 /// The hue component is expressed in degrees. Values outside of
 /// the 0-359° range will be normalized accordingly. The saturation
 /// and lightness components are expressed in percentages. Values
-/// outside of the 0-100% range will cause a panic.
+/// outside of the 0-100% range fail a debug assertion, and are
+/// clamped to 100% in release builds. Like [`rgb`](crate::rgb), it can
+/// be used to define constants.
 ///
 /// # Example
 /// ```
-/// use farver::{Color, hsl};
+/// use farver::{Color, hsl, HSL};
 ///
-/// let salmon = hsl(6, 93, 71);
+/// const SALMON: HSL = hsl(6, 93, 71);
 ///
-/// assert_eq!(salmon.to_css(), "hsl(6, 93%, 71%)");
+/// assert_eq!(SALMON.to_css(), "hsl(6, 93%, 71%)");
 /// ```
 ///
 /// [css-hsl]: https://www.w3.org/TR/css-color-3/#hsl-color
-pub fn hsl(h: i32, s: u8, l: u8) -> HSL {
+pub const fn hsl(h: i32, s: u8, l: u8) -> HSL {
     HSL {
-        h: deg(h),
-        s: percent(s),
-        l: percent(l),
+        h: FineAngle::from_angle(deg(h)),
+        s: FineRatio::from_percentage(s),
+        l: FineRatio::from_percentage(l),
     }
 }
 
@@ -33,9 +40,10 @@ pub fn hsl(h: i32, s: u8, l: u8) -> HSL {
 /// The hue component is expressed in degrees. Values outside of
 /// the 0-359° range will be normalized accordingly. The saturation
 /// and lightness components are expressed in percentages. Values
-/// outside of the 0-100% range will cause a panic. The alpha value
-/// is expressed as a float. Values outside of the 0.0-1.0 range will
-/// cause a panic.
+/// outside of the 0-100% range fail a debug assertion, and are
+/// clamped to 100% in release builds. The alpha value
+/// is expressed as a float. Values outside of the 0.0-1.0 range are
+/// clamped into it, and `NaN` gives a fully transparent color.
 ///
 /// # Example
 /// ```
@@ -44,37 +52,202 @@ pub fn hsl(h: i32, s: u8, l: u8) -> HSL {
 /// let salmon = hsla(6, 93, 71, 0.50);
 ///
 /// assert_eq!(salmon.to_css(), "hsla(6, 93%, 71%, 0.50)");
+/// assert_eq!(hsla(6, 93, 71, 2.0).to_css(), "hsla(6, 93%, 71%, 1.00)");
 /// ```
 ///
 /// [css-hsla]: https://www.w3.org/TR/css-color-3/#hsla-color
 pub fn hsla(h: i32, s: u8, l: u8, a: f32) -> HSLA {
     HSLA {
-        h: deg(h),
-        s: percent(s),
-        l: percent(l),
-        a: Ratio::from_f32(a),
+        h: FineAngle::from_angle(deg(h)),
+        s: FineRatio::from_percentage(s),
+        l: FineRatio::from_percentage(l),
+        a: Ratio::from(a),
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 /// A struct to represent how much hue, saturation, and luminosity should be added to create a color.
 /// The hue is a degree on the color wheel; 0 (or 360) is red, 120 is green, 240 is blue.
 /// A valid value for `h` must range between `0-360`.
 /// The saturation ranges between `0-100`, where `0` is completely desaturated, and `100` is full saturation.
 /// The luminosity ranges between `0-100`, where `0` is no light (black), and `100` is full light (white).
+/// The channels can be replaced with [`HSL::with_hue`], [`HSL::with_saturation`] and
+/// [`HSL::with_lightness`].
 ///
 /// For more, see the [CSS Color Spec](https://www.w3.org/TR/2018/REC-css-color-3-20180619/#hsl-color).
+///
+/// The hue is stored in hundredths of a degree, and the saturation and lightness in 65536
+/// steps, so every [`RGB`] color survives a round trip through `HSL` unchanged, and converting
+/// an `HSL` color to `RGB` and back settles after the first round trip. Whole percentages and
+/// degrees, as well as every [`Ratio`], are stored exactly. The getters and the CSS output
+/// round the channels to whole degrees and percentages.
+///
+/// The default `HSL` is black, `hsl(0, 0, 0)`.
 pub struct HSL {
     // hue
-    pub h: Angle,
+    pub(crate) h: FineAngle,
 
     // saturation
-    pub s: Ratio,
+    pub(crate) s: FineRatio,
 
     // luminosity
-    pub l: Ratio,
+    pub(crate) l: FineRatio,
+}
+
+impl HSL {
+    /// Constructs a HSL Color like [`hsl`], but returns an error when the saturation or
+    /// lightness fall outside of the 0-100% range, instead of failing a debug assertion
+    /// and clamping them.
+    /// The hue still wraps around the color wheel.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{hsl, HSL};
+    ///
+    /// assert_eq!(HSL::try_new(400, 93, 71), Ok(hsl(40, 93, 71)));
+    ///
+    /// let error = HSL::try_new(6, 150, 71).unwrap_err();
+    ///
+    /// assert_eq!(error.channel(), "saturation");
+    /// assert_eq!(error.to_string(), "saturation of 150 is outside of the 0-100% range");
+    /// ```
+    pub fn try_new(h: i32, s: i32, l: i32) -> Result<HSL, RangeError> {
+        Ok(HSL {
+            h: FineAngle::from_angle(deg(h)),
+            s: FineRatio::from_percentage(RangeError::check("saturation", s)?),
+            l: FineRatio::from_percentage(RangeError::check("lightness", l)?),
+        })
+    }
+
+    /// Returns the hue of `self` in degrees, within `0-359`.
+    pub fn hue(self) -> u16 {
+        self.h.degrees()
+    }
+
+    /// Returns the saturation of `self` as a percentage within `0-100`.
+    pub fn saturation_pct(self) -> u8 {
+        self.s.as_percentage()
+    }
+
+    /// Returns the lightness of `self` as a percentage within `0-100`.
+    pub fn lightness_pct(self) -> u8 {
+        self.l.as_percentage()
+    }
+
+    /// Returns a copy of `self` with its hue set to `h`. An [`Angle`] is always
+    /// normalized into the 0-359° range, so `deg(400)` sets a hue of 40°.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{deg, percent, hsl};
+    ///
+    /// let salmon = hsl(6, 93, 71);
+    ///
+    /// assert_eq!(salmon.with_hue(deg(400)), hsl(40, 93, 71));
+    /// assert_eq!(salmon.with_saturation(percent(60)), hsl(6, 60, 71));
+    /// assert_eq!(salmon.with_lightness(percent(150)), hsl(6, 93, 100));
+    /// ```
+    pub fn with_hue(self, h: Angle) -> Self {
+        Self {
+            h: h.into(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its saturation set to `s`. A [`Ratio`] never
+    /// exceeds 100%, so the result is always a valid color.
+    pub fn with_saturation(self, s: Ratio) -> Self {
+        Self {
+            s: s.into(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its lightness set to `l`. A [`Ratio`] never
+    /// exceeds 100%, so the result is always a valid color.
+    pub fn with_lightness(self, l: Ratio) -> Self {
+        Self {
+            l: l.into(),
+            ..self
+        }
+    }
+
+    /// Converts `self` to its CSS Color Module Level 4 string format like
+    /// [`Color::to_css_modern`], but with an explicit `deg` unit on the hue.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::hsl;
+    ///
+    /// assert_eq!(hsl(6, 93, 71).to_css_modern_deg(), "hsl(6deg 93% 71%)");
+    /// ```
+    pub fn to_css_modern_deg(self) -> String {
+        format!("hsl({}deg {} {})", self.h.degrees(), self.s, self.l)
+    }
+
+    /// Generates `count` colors with hues evenly spaced around the color wheel, starting
+    /// at 0°, all sharing the given `saturation` and `lightness`.
+    /// Useful for picking distinct colors for categorical data, such as the series of a chart.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{hsl, percent, HSL};
+    ///
+    /// let wheel = HSL::hue_wheel(3, percent(60), percent(40));
+    ///
+    /// assert_eq!(wheel, vec![hsl(0, 60, 40), hsl(120, 60, 40), hsl(240, 60, 40)]);
+    /// ```
+    pub fn hue_wheel(count: usize, saturation: Ratio, lightness: Ratio) -> Vec<HSL> {
+        (0..count)
+            .map(|i| HSL {
+                h: FineAngle::from_f32(i as f32 * 360.0 / count as f32),
+                s: saturation.into(),
+                l: lightness.into(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An error returned by [`HSL::try_new`] when a channel falls outside of its valid range.
+pub struct RangeError {
+    channel: &'static str,
+    value: i32,
+}
+
+impl RangeError {
+    // Returns `value` as a percentage if it falls within the 0-100% range.
+    fn check(channel: &'static str, value: i32) -> Result<u8, RangeError> {
+        match value {
+            0..=100 => Ok(value as u8),
+            _ => Err(RangeError { channel, value }),
+        }
+    }
+
+    /// Returns the name of the offending channel, such as `"saturation"`.
+    pub fn channel(&self) -> &'static str {
+        self.channel
+    }
+
+    /// Returns the out of range value that was given for the channel.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} is outside of the 0-100% range",
+            self.channel, self.value
+        )
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
 impl fmt::Display for HSL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "hsl({}, {}, {})", self.h.degrees(), self.s, self.l)
@@ -84,12 +257,28 @@ impl fmt::Display for HSL {
 impl Color for HSL {
     type Alpha = HSLA;
 
-    fn to_css(self) -> String {
-        self.to_string()
+    fn write_css(self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{}", self)
+    }
+
+    fn to_css_minimal(self) -> String {
+        self.to_css()
+    }
+
+    fn to_css_modern(self) -> String {
+        format!("hsl({} {} {})", self.h.degrees(), self.s, self.l)
     }
 
-    fn to_hex(self) -> String {
-        self.to_rgb().to_hex()
+    fn write_hex(self, w: &mut impl fmt::Write) -> fmt::Result {
+        self.to_rgb().write_hex(w)
+    }
+
+    fn to_hex_bare(self) -> String {
+        self.to_rgb().to_hex_bare()
+    }
+
+    fn to_u32(self) -> u32 {
+        self.to_rgb().to_u32()
     }
 
     fn to_rgb(self) -> RGB {
@@ -115,6 +304,10 @@ impl Color for HSL {
         }
     }
 
+    fn to_hsv(self) -> HSV {
+        self.to_hsla().to_hsv()
+    }
+
     fn saturate(self, amount: Ratio) -> Self {
         self.to_hsla().saturate(amount).to_hsl()
     }
@@ -123,6 +316,14 @@ impl Color for HSL {
         self.to_hsla().desaturate(amount).to_hsl()
     }
 
+    fn saturate_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().saturate_relative(amount).to_hsl()
+    }
+
+    fn desaturate_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().desaturate_relative(amount).to_hsl()
+    }
+
     fn lighten(self, amount: Ratio) -> Self {
         self.to_hsla().lighten(amount).to_hsl()
     }
@@ -131,6 +332,38 @@ impl Color for HSL {
         self.to_hsla().darken(amount).to_hsl()
     }
 
+    fn lighten_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().lighten_relative(amount).to_hsl()
+    }
+
+    fn darken_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().darken_relative(amount).to_hsl()
+    }
+
+    fn lighten_lab(self, amount: Ratio) -> Self {
+        self.to_hsla().lighten_lab(amount).to_hsl()
+    }
+
+    fn darken_lab(self, amount: Ratio) -> Self {
+        self.to_hsla().darken_lab(amount).to_hsl()
+    }
+
+    fn brighten(self, amount: Ratio) -> Self {
+        self.to_hsla().brighten(amount).to_hsl()
+    }
+
+    fn dim(self, amount: Ratio) -> Self {
+        self.to_hsla().dim(amount).to_hsl()
+    }
+
+    fn scale_saturation(self, amount: f32) -> Self {
+        self.to_hsla().scale_saturation(amount).to_hsl()
+    }
+
+    fn scale_lightness(self, amount: f32) -> Self {
+        self.to_hsla().scale_lightness(amount).to_hsl()
+    }
+
     fn fadein(self, amount: Ratio) -> Self::Alpha {
         self.to_hsla().fadein(amount)
     }
@@ -139,23 +372,45 @@ impl Color for HSL {
         self.to_hsla().fadeout(amount)
     }
 
-    fn fade(self, amount: Ratio) -> Self::Alpha {
+    fn fade(self, amount: impl Into<Ratio>) -> Self::Alpha {
         self.to_hsla().fade(amount)
     }
 
+    fn stack_alpha(self, times: u32) -> Self::Alpha {
+        self.to_hsla().stack_alpha(times)
+    }
+
     fn spin(self, amount: Angle) -> Self {
         self.to_hsla().spin(amount).to_hsl()
     }
 
-    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
+    fn accent(self) -> Self {
+        self.to_hsla().accent().to_hsl()
+    }
+
+    fn ensure_contrast<T: Color>(self, background: T, min_ratio: f32) -> Self {
+        self.to_hsla()
+            .ensure_contrast(background, min_ratio)
+            .to_hsl()
+    }
+
+    fn mix<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self::Alpha {
         self.to_hsla().mix(other, weight)
     }
 
-    fn tint(self, weight: Ratio) -> Self {
+    fn mix_opaque<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self {
+        self.to_hsla().mix_opaque(other, weight).to_hsl()
+    }
+
+    fn lerp<T: Color>(self, other: T, t: impl Into<Ratio>) -> Self::Alpha {
+        self.to_hsla().lerp(other, t)
+    }
+
+    fn tint(self, weight: impl Into<Ratio>) -> Self {
         self.to_hsla().tint(weight).to_hsl()
     }
 
-    fn shade(self, weight: Ratio) -> Self {
+    fn shade(self, weight: impl Into<Ratio>) -> Self {
         self.to_hsla().shade(weight).to_hsl()
     }
 
@@ -163,6 +418,26 @@ impl Color for HSL {
         self.to_hsla().greyscale().to_hsl()
     }
 
+    fn invert_lightness(self) -> Self {
+        self.to_hsla().invert_lightness().to_hsl()
+    }
+
+    fn is_grayscale(self) -> bool {
+        self.to_hsla().is_grayscale()
+    }
+
+    fn map_rgb(self, f: impl Fn(Ratio) -> Ratio) -> Self {
+        self.to_hsla().map_rgb(f).to_hsl()
+    }
+
+    fn warm(self, amount: Ratio) -> Self {
+        self.to_hsla().warm(amount).to_hsl()
+    }
+
+    fn cool(self, amount: Ratio) -> Self {
+        self.to_hsla().cool(amount).to_hsl()
+    }
+
     #[cfg(feature = "ansi_term")]
     fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
         &self,
@@ -179,13 +454,69 @@ impl Color for HSL {
 
 impl From<HSL> for (u16, u8, u8) {
     fn from(v: HSL) -> Self {
-        (v.h.degrees(), v.s.as_u8(), v.l.as_u8())
+        (
+            v.h.degrees(),
+            v.s.to_ratio().as_u8(),
+            v.l.to_ratio().as_u8(),
+        )
     }
 }
 
+/// Converts a hue in degrees, and a saturation and lightness within `0.0-1.0`,
+/// into normalized red, green and blue channels within `0.0-1.0`. Hues outside
+/// of the 0-360° range are normalized, while infinite and `NaN` hues give the
+/// grey of lightness `l`, as if `s` were zero. This is the conversion behind
+/// [`Color::to_rgb`] for `HSL` colors, before the channels are rounded into an
+/// [`RGB`].
+///
+/// # Example
+/// ```
+/// use farver::hsl_to_rgb;
+///
+/// assert_eq!(hsl_to_rgb(0.0, 1.0, 0.5), (1.0, 0.0, 0.0));
+/// assert_eq!(hsl_to_rgb(600.0, 1.0, 0.5), (0.0, 0.0, 1.0));
+/// assert_eq!(hsl_to_rgb(120.0, 0.0, 0.5), (0.5, 0.5, 0.5));
+/// ```
+pub fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    // If there is no saturation, the color is a shade of grey.
+    // We can convert the luminosity and set r, g, and b to that value.
+    // Infinite and NaN hues don't point anywhere on the color wheel, so they're grey too.
+    if s == 0.0 || !h.is_finite() {
+        return (l, l, l);
+    }
+
+    // If the color is not a grey, then we need to create a temporary variable to continue with the algorithm.
+    // If the luminosity is less than 50%, we add 1.0 to the saturation and multiply by the luminosity.
+    // Otherwise, we add the luminosity and saturation, and subtract the product of luminosity and saturation from it.
+    let temp_1 = if l < 0.5 {
+        l * (1.0 + s)
+    } else {
+        (l + s) - (l * s)
+    };
+
+    // Another temporary variable.
+    let temp_2 = (2.0 * l) - temp_1;
+
+    // Then rotate the circle clockwise by 1/3 for the red value, and by 2/3rds for the blue value.
+    let temporary_r = normalize_degrees(h + 120.0);
+    let temporary_g = normalize_degrees(h);
+    let temporary_b = normalize_degrees(h - 120.0);
+
+    let red = to_rgb_value(temporary_r, temp_1, temp_2);
+    let green = to_rgb_value(temporary_g, temp_1, temp_2);
+    let blue = to_rgb_value(temporary_b, temp_1, temp_2);
+
+    (red, green, blue)
+}
+
+// Wraps a hue in degrees into the 0-360° range.
+fn normalize_degrees(degrees: f32) -> f32 {
+    rem_euclid(degrees, 360.0)
+}
+
 // A function to convert an HSL value (either h, s, or l) into the equivalent, valid RGB value.
-fn to_rgb_value(val: u16, temp_1: f32, temp_2: f32) -> f32 {
-    let value = val as f32 / 360.0;
+fn to_rgb_value(degrees: f32, temp_1: f32, temp_2: f32) -> f32 {
+    let value = degrees / 360.0;
 
     if value > (2.0 / 3.0) {
         // value > 0.66667
@@ -202,27 +533,110 @@ fn to_rgb_value(val: u16, temp_1: f32, temp_2: f32) -> f32 {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 /// A struct to represent how much hue, saturation, and luminosity should be added to create a color.
 /// Also handles alpha specifications.
 ///
 /// A valid value for `h` must range between `0-360`.
 /// The saturation ranges between `0-100`, where `0` is completely desaturated, and `100` is full saturation.
 /// The luminosity ranges between `0-100`, where `0` is no light (black), and `100` is full light (white).
+/// The channels can be replaced with [`HSLA::with_hue`], [`HSLA::with_saturation`] and
+/// [`HSLA::with_lightness`].
 ///
 /// For more, see the [CSS Color Spec](https://www.w3.org/TR/2018/REC-css-color-3-20180619/#hsla-color).
+///
+/// The default `HSLA` is transparent black, `hsla(0, 0, 0, 0.0)`, just like the default [`RGBA`].
 pub struct HSLA {
     // hue
-    pub h: Angle,
+    pub(crate) h: FineAngle,
 
     // saturation
-    pub s: Ratio,
+    pub(crate) s: FineRatio,
 
     // luminosity
-    pub l: Ratio,
+    pub(crate) l: FineRatio,
 
     // alpha
-    pub a: Ratio,
+    pub(crate) a: Ratio,
+}
+
+impl HSLA {
+    /// Returns the hue of `self` in degrees, within `0-359`.
+    pub fn hue(self) -> u16 {
+        self.h.degrees()
+    }
+
+    /// Returns the saturation of `self` as a percentage within `0-100`.
+    pub fn saturation_pct(self) -> u8 {
+        self.s.as_percentage()
+    }
+
+    /// Returns the lightness of `self` as a percentage within `0-100`.
+    pub fn lightness_pct(self) -> u8 {
+        self.l.as_percentage()
+    }
+
+    /// Returns a copy of `self` with its hue set to `h`. An [`Angle`] is always
+    /// normalized into the 0-359° range, so `deg(400)` sets a hue of 40°.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{deg, percent, hsla};
+    ///
+    /// let salmon = hsla(6, 93, 71, 0.5);
+    ///
+    /// assert_eq!(salmon.with_hue(deg(400)), hsla(40, 93, 71, 0.5));
+    /// assert_eq!(salmon.with_saturation(percent(60)), hsla(6, 60, 71, 0.5));
+    /// assert_eq!(salmon.with_lightness(percent(150)), hsla(6, 93, 100, 0.5));
+    /// ```
+    pub fn with_hue(self, h: Angle) -> Self {
+        Self {
+            h: h.into(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its saturation set to `s`. A [`Ratio`] never
+    /// exceeds 100%, so the result is always a valid color.
+    pub fn with_saturation(self, s: Ratio) -> Self {
+        Self {
+            s: s.into(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its lightness set to `l`. A [`Ratio`] never
+    /// exceeds 100%, so the result is always a valid color.
+    pub fn with_lightness(self, l: Ratio) -> Self {
+        Self {
+            l: l.into(),
+            ..self
+        }
+    }
+
+    /// Returns the alpha channel of `self` within `0.0-1.0`.
+    pub fn alpha_f32(self) -> f32 {
+        self.a.as_f32()
+    }
+
+    /// Converts `self` to its CSS Color Module Level 4 string format like
+    /// [`Color::to_css_modern`], but with an explicit `deg` unit on the hue.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::hsla;
+    ///
+    /// assert_eq!(hsla(6, 93, 71, 0.5).to_css_modern_deg(), "hsl(6deg 93% 71% / 50%)");
+    /// ```
+    pub fn to_css_modern_deg(self) -> String {
+        format!(
+            "hsl({}deg {} {} / {})",
+            self.h.degrees(),
+            self.s,
+            self.l,
+            self.a
+        )
+    }
 }
 
 impl fmt::Display for HSLA {
@@ -241,64 +655,54 @@ impl fmt::Display for HSLA {
 impl Color for HSLA {
     type Alpha = Self;
 
-    fn to_css(self) -> String {
-        self.to_string()
+    fn write_css(self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{}", self)
     }
 
-    fn to_hex(self) -> String {
-        self.to_rgba().to_hex()
+    fn to_css_minimal(self) -> String {
+        if self.to_u32() == 0 {
+            String::from("transparent")
+        } else if self.a.as_u8() == u8::MAX {
+            self.to_hsl().to_css()
+        } else {
+            self.to_css()
+        }
     }
 
-    fn to_rgb(self) -> RGB {
-        self.to_rgba().to_rgb()
+    fn to_css_modern(self) -> String {
+        format!(
+            "hsl({} {} {} / {})",
+            self.h.degrees(),
+            self.s,
+            self.l,
+            self.a
+        )
     }
 
-    fn to_rgba(self) -> RGBA {
-        let HSLA { h, s, l, a } = self;
-
-        // If there is no saturation, the color is a shade of grey.
-        // We can convert the luminosity and set r, g, and b to that value.
-        if s == percent(0) {
-            return RGBA {
-                r: l,
-                g: l,
-                b: l,
-                a,
-            };
-        }
-
-        let s = s.as_f32();
-        let l = l.as_f32();
-
-        // If the color is not a grey, then we need to create a temporary variable to continue with the algorithm.
-        // If the luminosity is less than 50%, we add 1.0 to the saturation and multiply by the luminosity.
-        // Otherwise, we add the luminosity and saturation, and subtract the product of luminosity and saturation from it.
-        let temp_1 = if l < 0.5 {
-            l * (1.0 + s)
-        } else {
-            (l + s) - (l * s)
-        };
+    fn write_hex(self, w: &mut impl fmt::Write) -> fmt::Result {
+        self.to_rgba().write_hex(w)
+    }
 
-        // Another temporary variable.
-        let temp_2 = (2.0 * l) - temp_1;
+    fn to_hex_bare(self) -> String {
+        self.to_rgba().to_hex_bare()
+    }
 
-        // Create a rotation of 120 degrees in order to divide the angle into thirds.
-        let rotation = Angle::new(120);
+    fn to_u32(self) -> u32 {
+        self.to_rgba().to_u32()
+    }
 
-        // Then rotate the circle clockwise by 1/3 for the red value, and by 2/3rds for the blue value.
-        let temporary_r = (h + rotation).degrees();
-        let temporary_g = h.degrees();
-        let temporary_b = (h - rotation).degrees();
+    fn to_rgb(self) -> RGB {
+        self.to_rgba().to_rgb()
+    }
 
-        let red = to_rgb_value(temporary_r, temp_1, temp_2);
-        let green = to_rgb_value(temporary_g, temp_1, temp_2);
-        let blue = to_rgb_value(temporary_b, temp_1, temp_2);
+    fn to_rgba(self) -> RGBA {
+        let (r, g, b) = hsl_to_rgb(self.h.as_f32(), self.s.as_f32(), self.l.as_f32());
 
         RGBA {
-            r: Ratio::from_f32(red),
-            g: Ratio::from_f32(green),
-            b: Ratio::from_f32(blue),
-            a,
+            r: Ratio::from_f32(r),
+            g: Ratio::from_f32(g),
+            b: Ratio::from_f32(b),
+            a: self.a,
         }
     }
 
@@ -311,6 +715,20 @@ impl Color for HSLA {
         self
     }
 
+    fn to_hsv(self) -> HSV {
+        let s = self.s.as_f32();
+        let l = self.l.as_f32();
+        let v = l + s * l.min(1.0 - l);
+
+        let s = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+
+        HSV {
+            h: self.h.to_angle(),
+            s: Ratio::from_f32(s.clamp(0.0, 1.0)),
+            v: Ratio::from_f32(v),
+        }
+    }
+
     fn saturate(self, amount: Ratio) -> Self {
         let HSLA { h, s, l, a } = self;
 
@@ -355,6 +773,80 @@ impl Color for HSLA {
         }
     }
 
+    fn saturate_relative(self, amount: Ratio) -> Self {
+        let HSLA { h, s, l, a } = self;
+
+        HSLA {
+            h,
+            s: s + s * amount,
+            l,
+            a,
+        }
+    }
+
+    fn desaturate_relative(self, amount: Ratio) -> Self {
+        let HSLA { h, s, l, a } = self;
+
+        HSLA {
+            h,
+            s: s - s * amount,
+            l,
+            a,
+        }
+    }
+
+    fn lighten_relative(self, amount: Ratio) -> Self {
+        let HSLA { h, s, l, a } = self;
+
+        HSLA {
+            h,
+            s,
+            l: l + l * amount,
+            a,
+        }
+    }
+
+    fn darken_relative(self, amount: Ratio) -> Self {
+        let HSLA { h, s, l, a } = self;
+
+        HSLA {
+            h,
+            s,
+            l: l - l * amount,
+            a,
+        }
+    }
+
+    fn lighten_lab(self, amount: Ratio) -> Self {
+        self.to_rgba().lighten_lab(amount).to_hsla()
+    }
+
+    fn darken_lab(self, amount: Ratio) -> Self {
+        self.to_rgba().darken_lab(amount).to_hsla()
+    }
+
+    fn brighten(self, amount: Ratio) -> Self {
+        self.to_rgba().brighten(amount).to_hsla()
+    }
+
+    fn dim(self, amount: Ratio) -> Self {
+        self.to_rgba().dim(amount).to_hsla()
+    }
+
+    fn scale_saturation(self, amount: f32) -> Self {
+        HSLA {
+            s: scale(self.s, amount),
+            ..self
+        }
+    }
+
+    fn scale_lightness(self, amount: f32) -> Self {
+        HSLA {
+            l: scale(self.l, amount),
+            ..self
+        }
+    }
+
     fn fadein(self, amount: Ratio) -> Self {
         self.fade(self.a + amount)
     }
@@ -363,9 +855,20 @@ impl Color for HSLA {
         self.fade(self.a - amount)
     }
 
-    fn fade(self, amount: Ratio) -> Self::Alpha {
+    fn fade(self, amount: impl Into<Ratio>) -> Self::Alpha {
         let HSLA { h, s, l, .. } = self;
-        HSLA { h, s, l, a: amount }
+        HSLA {
+            h,
+            s,
+            l,
+            a: amount.into(),
+        }
+    }
+
+    fn stack_alpha(self, times: u32) -> Self {
+        let transparency = 1.0 - self.a.as_f32();
+
+        self.fade(1.0 - powf(transparency, times as f32))
     }
 
     fn spin(self, amount: Angle) -> Self {
@@ -379,15 +882,65 @@ impl Color for HSLA {
         }
     }
 
-    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
+    fn ensure_contrast<T: Color>(self, background: T, min_ratio: f32) -> Self {
+        let background = background.to_rgb();
+        let with_lightness = |l: u8| self.with_lightness(Ratio::from_u8(l));
+        let has_contrast = |l: &u8| with_lightness(*l).contrast_ratio(background) >= min_ratio;
+
+        if self.contrast_ratio(background) >= min_ratio {
+            return self;
+        }
+
+        let l = self.l.to_ratio().as_u8();
+
+        // Find the nearest lightness with enough contrast on either side of the current one.
+        let lighter = (l..=u8::MAX).find(has_contrast);
+        let darker = (0..l).rev().find(has_contrast);
+
+        match (lighter, darker) {
+            (Some(lighter), Some(darker)) if lighter - l <= l - darker => with_lightness(lighter),
+            (_, Some(darker)) => with_lightness(darker),
+            (Some(lighter), None) => with_lightness(lighter),
+            (None, None) => {
+                let white = with_lightness(u8::MAX);
+                let black = with_lightness(0);
+
+                if white.contrast_ratio(background) >= black.contrast_ratio(background) {
+                    white
+                } else {
+                    black
+                }
+            }
+        }
+    }
+
+    fn accent(self) -> Self {
+        let spun = self.spin(deg(30));
+
+        if self.l < FineRatio::from_percentage(50) {
+            spun.lighten(percent(20))
+        } else {
+            spun.darken(percent(20))
+        }
+    }
+
+    fn mix<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self::Alpha {
         self.to_rgba().mix(other, weight).to_hsla()
     }
 
-    fn tint(self, weight: Ratio) -> Self {
+    fn mix_opaque<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self {
+        self.to_rgba().mix_opaque(other, weight).to_hsla()
+    }
+
+    fn lerp<T: Color>(self, other: T, t: impl Into<Ratio>) -> Self::Alpha {
+        self.to_rgba().lerp(other, t).to_hsla()
+    }
+
+    fn tint(self, weight: impl Into<Ratio>) -> Self {
         self.to_rgba().tint(weight).to_hsla()
     }
 
-    fn shade(self, weight: Ratio) -> Self {
+    fn shade(self, weight: impl Into<Ratio>) -> Self {
         self.to_rgba().shade(weight).to_hsla()
     }
 
@@ -396,12 +949,35 @@ impl Color for HSLA {
 
         HSLA {
             h,
-            s: percent(0),
+            s: FineRatio::ZERO,
             l,
             a,
         }
     }
 
+    fn invert_lightness(self) -> Self {
+        HSLA {
+            l: FineRatio::FULL - self.l,
+            ..self
+        }
+    }
+
+    fn is_grayscale(self) -> bool {
+        self.s == FineRatio::ZERO || self.to_rgba().is_grayscale()
+    }
+
+    fn map_rgb(self, f: impl Fn(Ratio) -> Ratio) -> Self {
+        self.to_rgba().map_rgb(f).to_hsla()
+    }
+
+    fn warm(self, amount: Ratio) -> Self {
+        self.to_rgba().warm(amount).to_hsla()
+    }
+
+    fn cool(self, amount: Ratio) -> Self {
+        self.to_rgba().cool(amount).to_hsla()
+    }
+
     #[cfg(feature = "ansi_term")]
     fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
         &self,
@@ -418,6 +994,24 @@ impl Color for HSLA {
 
 impl From<HSLA> for (u16, u8, u8, f32) {
     fn from(v: HSLA) -> Self {
-        (v.h.degrees(), v.s.as_u8(), v.l.as_u8(), v.a.as_f32())
+        (
+            v.h.degrees(),
+            v.s.to_ratio().as_u8(),
+            v.l.to_ratio().as_u8(),
+            v.a.as_f32(),
+        )
+    }
+}
+
+// Moves `value` towards 100% by `amount` of the distance when positive, and
+// towards 0% when negative.
+fn scale(value: FineRatio, amount: f32) -> FineRatio {
+    let amount = amount.clamp(-1.0, 1.0);
+    let value = value.as_f32();
+
+    if amount >= 0.0 {
+        FineRatio::from_f32(value + (1.0 - value) * amount)
+    } else {
+        FineRatio::from_f32(value + value * amount)
     }
 }
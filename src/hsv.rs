@@ -0,0 +1,244 @@
+use super::{deg, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use crate::fine::FineRatio;
+use core::fmt;
+
+/// Constructs a HSV Color from numerical values.
+///
+/// The hue component is expressed in degrees. Values outside of
+/// the 0-359° range will be normalized accordingly. The saturation
+/// and value components are expressed in percentages. Values
+/// outside of the 0-100% range fail a debug assertion, and are
+/// clamped to 100% in release builds.
+///
+/// # Example
+/// ```
+/// use farver::{hsv, rgb};
+///
+/// let salmon = hsv(6, 54, 98);
+///
+/// assert_eq!(salmon.to_string(), "hsv(6, 54%, 98%)");
+/// assert_eq!(salmon.to_rgb(), rgb(250, 128, 115));
+/// ```
+pub fn hsv(h: i32, s: u8, v: u8) -> HSV {
+    HSV {
+        h: deg(h),
+        s: Ratio::from_percentage(s),
+        v: Ratio::from_percentage(v),
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+/// A struct to represent a color by its hue, saturation, and value, as used by most color pickers.
+/// HSV is also known as HSB, with the value called brightness.
+/// The hue is a degree on the color wheel, just like in [`HSL`].
+/// The saturation ranges between `0-100`, where `0` is completely desaturated, and `100` is full saturation.
+/// The value ranges between `0-100`, where `0` is black, and `100` is the brightest color of the hue
+/// and saturation. Unlike the lightness of [`HSL`], a value of `100` is only white when the
+/// saturation is `0`.
+///
+/// HSV isn't part of CSS, so it can't be used in stylesheets directly; convert it with
+/// [`HSV::to_rgb`] or [`HSV::to_hsl`] first. Any [`Color`] converts into HSV with [`Color::to_hsv`].
+///
+/// The default `HSV` is black, `hsv(0, 0, 0)`.
+pub struct HSV {
+    // hue
+    pub(crate) h: Angle,
+
+    // saturation
+    pub(crate) s: Ratio,
+
+    // value
+    pub(crate) v: Ratio,
+}
+
+impl HSV {
+    /// Returns the hue of `self` in degrees, within `0-359`.
+    pub fn hue(self) -> u16 {
+        self.h.degrees()
+    }
+
+    /// Returns the saturation of `self` as a percentage within `0-100`.
+    pub fn saturation_pct(self) -> u8 {
+        self.s.as_percentage()
+    }
+
+    /// Returns the value of `self` as a percentage within `0-100`.
+    pub fn value_pct(self) -> u8 {
+        self.v.as_percentage()
+    }
+
+    /// Converts `self` into its RGB representation.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{hsv, rgb};
+    ///
+    /// assert_eq!(hsv(120, 100, 100).to_rgb(), rgb(0, 255, 0));
+    /// assert_eq!(hsv(120, 0, 50).to_rgb(), rgb(128, 128, 128));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        let h = self.h.degrees() as f32 / 60.0;
+        let s = self.s.as_f32();
+        let v = self.v.as_f32();
+
+        let channel = |n: f32| {
+            let k = (n + h) % 6.0;
+
+            Ratio::from_f32(v - v * s * k.min(4.0 - k).clamp(0.0, 1.0))
+        };
+
+        RGB {
+            r: channel(5.0),
+            g: channel(3.0),
+            b: channel(1.0),
+        }
+    }
+
+    /// Converts `self` into its HSL representation, keeping its hue.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{hsl, hsv};
+    ///
+    /// assert_eq!(hsv(0, 100, 100).to_hsl(), hsl(0, 100, 50));
+    /// assert_eq!(hsv(200, 0, 100).to_hsl(), hsl(200, 0, 100));
+    /// ```
+    pub fn to_hsl(self) -> HSL {
+        let s = self.s.as_f32();
+        let v = self.v.as_f32();
+        let l = v * (1.0 - s / 2.0);
+
+        let s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (v - l) / l.min(1.0 - l)
+        };
+
+        HSL {
+            h: self.h.into(),
+            s: FineRatio::from_f32(s),
+            l: FineRatio::from_f32(l),
+        }
+    }
+}
+
+impl fmt::Display for HSV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "hsv({}, {}, {})", self.h.degrees(), self.s, self.v)
+    }
+}
+
+impl From<HSV> for RGB {
+    fn from(color: HSV) -> RGB {
+        color.to_rgb()
+    }
+}
+
+impl From<HSV> for RGBA {
+    fn from(color: HSV) -> RGBA {
+        color.to_rgb().to_rgba()
+    }
+}
+
+impl From<HSV> for HSL {
+    fn from(color: HSV) -> HSL {
+        color.to_hsl()
+    }
+}
+
+impl From<HSV> for HSLA {
+    fn from(color: HSV) -> HSLA {
+        color.to_hsl().to_hsla()
+    }
+}
+
+impl From<HSL> for HSV {
+    fn from(color: HSL) -> HSV {
+        color.to_hsv()
+    }
+}
+
+impl From<RGB> for HSV {
+    fn from(color: RGB) -> HSV {
+        color.to_hsv()
+    }
+}
+
+impl From<RGBA> for HSV {
+    fn from(color: RGBA) -> HSV {
+        color.to_hsv()
+    }
+}
+
+impl From<HSLA> for HSV {
+    fn from(color: HSLA) -> HSV {
+        color.to_hsv()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hsv, HSV};
+    use crate::approx::ApproximatelyEq;
+    use crate::{hsl, rgb, rgba, Color, HSL, RGB};
+
+    #[test]
+    fn converts_the_poles() {
+        assert_eq!(rgb(0, 0, 0).to_hsv(), hsv(0, 0, 0));
+        assert_eq!(rgb(255, 255, 255).to_hsv(), hsv(0, 0, 100));
+        assert_eq!(hsl(0, 0, 0).to_hsv(), hsv(0, 0, 0));
+        assert_eq!(hsl(0, 0, 100).to_hsv(), hsv(0, 0, 100));
+
+        assert_eq!(hsv(0, 0, 0).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(hsv(0, 0, 100).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(hsv(0, 100, 0).to_hsl(), hsl(0, 0, 0));
+        assert_eq!(hsv(0, 0, 100).to_hsl(), hsl(0, 0, 100));
+    }
+
+    #[test]
+    fn converts_pure_hues() {
+        let hues = [
+            (0, rgb(255, 0, 0)),
+            (60, rgb(255, 255, 0)),
+            (120, rgb(0, 255, 0)),
+            (180, rgb(0, 255, 255)),
+            (240, rgb(0, 0, 255)),
+            (300, rgb(255, 0, 255)),
+        ];
+
+        for (hue, color) in hues {
+            assert_eq!(color.to_hsv(), hsv(hue, 100, 100));
+            assert_eq!(hsv(hue, 100, 100).to_rgb(), color);
+            assert_eq!(hsv(hue, 100, 100).to_hsl(), hsl(hue, 100, 50));
+            // A lightness of 50% is stored as 128/255, leaving a sliver of white in the color.
+            assert!(hsl(hue, 100, 50)
+                .to_hsv()
+                .approximately_eq(hsv(hue, 100, 100), 0));
+        }
+    }
+
+    #[test]
+    fn agrees_with_rgb_and_hsl() {
+        let salmon = rgb(250, 128, 114);
+        let hsv = salmon.to_hsv();
+
+        assert_eq!(hsv.to_string(), "hsv(6, 55%, 98%)");
+        assert!(RGB::from(hsv).approximately_eq(salmon, 1));
+        assert!(HSL::from(hsv).approximately_eq(salmon.to_hsl(), 1));
+        assert!(HSV::from(salmon.to_hsl()).approximately_eq(hsv, 0));
+    }
+
+    #[test]
+    fn converts_from_every_model() {
+        let salmon = rgb(250, 128, 114);
+        let hsv = salmon.to_hsv();
+
+        assert_eq!(HSV::from(salmon), hsv);
+        assert_eq!(HSV::from(salmon.to_rgba()), hsv);
+        assert_eq!(HSV::from(salmon.to_hsl()), salmon.to_hsl().to_hsv());
+        assert_eq!(HSV::from(salmon.to_hsla()), salmon.to_hsla().to_hsv());
+
+        // Converting into `HSV` drops the alpha channel.
+        assert_eq!(HSV::from(rgba(250, 128, 114, 0.5)), hsv);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::lab::to_linear;
 use crate::{hsl, hsla, rgb, rgba, Color};
 use bevy::render::color::Color as BevyColor;
 
@@ -24,7 +25,7 @@ impl Into<BevyColor> for crate::HSL {
 impl Into<BevyColor> for crate::HSLA {
     fn into(self) -> BevyColor {
         BevyColor::Hsla {
-            hue: self.h.degrees() as f32,
+            hue: self.h.as_f32(),
             saturation: self.s.as_f32(),
             lightness: self.l.as_f32(),
             alpha: self.a.as_f32(),
@@ -32,6 +33,40 @@ impl Into<BevyColor> for crate::HSLA {
     }
 }
 
+impl crate::RGB {
+    /// Converts `self` into a Bevy [`Color::RgbaLinear`](BevyColor::RgbaLinear), decoding
+    /// the sRGB channels into linear light. Bevy's shaders work in linear light, so this is
+    /// what they receive whichever variant is used; `Into<bevy::render::color::Color>`
+    /// gives a `Color::Rgba` instead, which Bevy linearizes itself.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::render::color::Color as BevyColor;
+    /// use farver::rgb;
+    ///
+    /// let white = rgb(255, 255, 255).to_bevy_linear();
+    ///
+    /// assert_eq!(white, BevyColor::rgba_linear(1.0, 1.0, 1.0, 1.0));
+    /// ```
+    pub fn to_bevy_linear(self) -> BevyColor {
+        self.to_rgba().to_bevy_linear()
+    }
+}
+
+impl crate::RGBA {
+    /// Converts `self` into a Bevy [`Color::RgbaLinear`](BevyColor::RgbaLinear), decoding
+    /// the sRGB channels into linear light, while the alpha channel is kept as is.
+    /// See [`RGB::to_bevy_linear`](crate::RGB::to_bevy_linear).
+    pub fn to_bevy_linear(self) -> BevyColor {
+        BevyColor::RgbaLinear {
+            red: to_linear(self.r.as_f32()),
+            green: to_linear(self.g.as_f32()),
+            blue: to_linear(self.b.as_f32()),
+            alpha: self.a.as_f32(),
+        }
+    }
+}
+
 #[cfg(test)]
 fn test() {
     let expected_hsla = BevyColor::hsla(128., 1., 1., 1.);
@@ -46,3 +81,50 @@ fn test() {
     assert_eq!(expected_rgba, actual_rgba.into());
     assert_eq!(expected_rgba, actual_rgb.into());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BevyColor;
+    use crate::{deg, hsl, hsla, rgb, rgba, Color};
+
+    #[test]
+    fn linearizes_like_bevy() {
+        for color in [
+            rgba(250, 128, 114, 0.5),
+            rgba(0, 0, 0, 1.0),
+            rgba(255, 255, 255, 0.0),
+            rgba(10, 3, 200, 0.25),
+        ] {
+            let expected: BevyColor = color.into();
+            let [red, green, blue, alpha] = expected.as_rgba_linear().as_linear_rgba_f32();
+            let [r, g, b, a] = color.to_bevy_linear().as_linear_rgba_f32();
+
+            assert!((red - r).abs() < 1e-6);
+            assert!((green - g).abs() < 1e-6);
+            assert!((blue - b).abs() < 1e-6);
+            assert_eq!(alpha, a);
+        }
+
+        assert_eq!(
+            rgb(250, 128, 114).to_bevy_linear(),
+            rgb(250, 128, 114).to_rgba().to_bevy_linear()
+        );
+    }
+
+    #[test]
+    fn passes_normalized_hues() {
+        // An `Angle` is always normalized, so spinning past a full turn wraps the hue around.
+        // Lightness is stored in 65536 steps, so 50% lands on the step just above one half.
+        let spun: BevyColor = hsla(300, 100, 50, 1.0).spin(deg(100)).into();
+        let negative: BevyColor = hsl(20, 100, 50).spin(deg(-740)).into();
+
+        assert_eq!(spun, BevyColor::hsla(40.0, 1.0, 32768.0 / 65535.0, 1.0));
+        assert_eq!(negative, BevyColor::hsla(0.0, 1.0, 32768.0 / 65535.0, 1.0));
+
+        for degrees in [-720, -1, 359, 360, 1000] {
+            let color: BevyColor = hsl(0, 100, 50).spin(deg(degrees)).into();
+
+            assert!(matches!(color, BevyColor::Hsla { hue, .. } if (0.0..360.0).contains(&hue)));
+        }
+    }
+}
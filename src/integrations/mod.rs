@@ -4,5 +4,9 @@ mod ansi_term;
 mod bevy;
 #[cfg(feature = "palette")]
 mod palette;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
 #[cfg(feature = "serde")]
-mod serde;
+pub(crate) mod serde;
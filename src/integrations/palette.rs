@@ -22,7 +22,7 @@ use palette::Hsl;
 impl Into<Hsl> for crate::HSL {
     fn into(self) -> Hsl {
         Hsl::new(
-            RgbHue::from_degrees(self.h.degrees().into()),
+            RgbHue::from_degrees(self.h.as_f32()),
             self.s.as_f32(),
             self.l.as_f32(),
         )
@@ -33,7 +33,7 @@ use palette::Hsla;
 impl Into<Hsla> for crate::HSLA {
     fn into(self) -> Hsla {
         Hsla::new(
-            RgbHue::from_degrees(self.h.degrees().into()),
+            RgbHue::from_degrees(self.h.as_f32()),
             self.s.as_f32(),
             self.l.as_f32(),
             self.a.as_f32(),
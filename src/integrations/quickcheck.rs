@@ -0,0 +1,136 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::fine::{FineAngle, FineRatio};
+use crate::{deg, Color, Ratio, HSL, HSLA, RGB, RGBA};
+
+// Picks a hue within `0-359°`, a saturation or lightness within `0-100%`, and any alpha.
+fn arbitrary_hsla(g: &mut Gen) -> (u16, u8, u8, u8) {
+    (
+        u16::arbitrary(g) % 360,
+        u8::arbitrary(g) % 101,
+        u8::arbitrary(g) % 101,
+        u8::arbitrary(g),
+    )
+}
+
+fn to_hsla((h, s, l, a): (u16, u8, u8, u8)) -> HSLA {
+    HSLA {
+        h: FineAngle::from_angle(deg(h as i32)),
+        s: FineRatio::from_percentage(s),
+        l: FineRatio::from_percentage(l),
+        a: Ratio::from_u8(a),
+    }
+}
+
+impl Arbitrary for RGB {
+    fn arbitrary(g: &mut Gen) -> Self {
+        RGB {
+            r: Ratio::from_u8(u8::arbitrary(g)),
+            g: Ratio::from_u8(u8::arbitrary(g)),
+            b: Ratio::from_u8(u8::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let channels = (self.r.as_u8(), self.g.as_u8(), self.b.as_u8());
+
+        Box::new(channels.shrink().map(|(r, g, b)| RGB {
+            r: Ratio::from_u8(r),
+            g: Ratio::from_u8(g),
+            b: Ratio::from_u8(b),
+        }))
+    }
+}
+
+impl Arbitrary for RGBA {
+    fn arbitrary(g: &mut Gen) -> Self {
+        RGBA {
+            r: Ratio::from_u8(u8::arbitrary(g)),
+            g: Ratio::from_u8(u8::arbitrary(g)),
+            b: Ratio::from_u8(u8::arbitrary(g)),
+            a: Ratio::from_u8(u8::arbitrary(g)),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let channels = (
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8(),
+            self.a.as_u8(),
+        );
+
+        Box::new(channels.shrink().map(|(r, g, b, a)| RGBA {
+            r: Ratio::from_u8(r),
+            g: Ratio::from_u8(g),
+            b: Ratio::from_u8(b),
+            a: Ratio::from_u8(a),
+        }))
+    }
+}
+
+impl Arbitrary for HSL {
+    fn arbitrary(g: &mut Gen) -> Self {
+        HSLA::arbitrary(g).to_hsl()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_hsla().shrink().map(|color| color.to_hsl()))
+    }
+}
+
+impl Arbitrary for HSLA {
+    fn arbitrary(g: &mut Gen) -> Self {
+        to_hsla(arbitrary_hsla(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let components = (
+            self.h.degrees(),
+            self.saturation_pct(),
+            self.lightness_pct(),
+            self.a.as_u8(),
+        );
+
+        // Shrinking only ever moves the components towards zero, so they stay in range.
+        Box::new(components.shrink().map(to_hsla))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+
+    use crate::{Color, HSL, HSLA, RGB, RGBA};
+
+    quickcheck! {
+        fn hsl_components_are_in_range(color: HSL) -> bool {
+            color.hue() < 360 && color.saturation_pct() <= 100 && color.lightness_pct() <= 100
+        }
+
+        fn hsla_components_are_in_range(color: HSLA) -> bool {
+            color.hue() < 360 && color.saturation_pct() <= 100 && color.lightness_pct() <= 100
+        }
+
+        fn rgb_round_trips_through_hex(color: RGB) -> bool {
+            color.to_hex().parse::<RGB>() == Ok(color)
+        }
+
+        fn rgba_alpha_is_a_fraction(color: RGBA) -> bool {
+            (0.0..=1.0).contains(&color.a.as_f32())
+        }
+    }
+
+    #[test]
+    fn shrunk_hsla_stays_in_range() {
+        let mut g = Gen::new(100);
+
+        for _ in 0..100 {
+            for color in HSLA::arbitrary(&mut g).shrink() {
+                assert!(color.hue() < 360);
+                assert!(color.saturation_pct() <= 100);
+                assert!(color.lightness_pct() <= 100);
+            }
+        }
+    }
+}
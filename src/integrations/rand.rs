@@ -0,0 +1,107 @@
+use rand::Rng;
+
+use crate::{hsl, Ratio, HSL, RGB};
+
+impl RGB {
+    /// Generates a color with every channel picked uniformly at random, using the thread-local
+    /// random number generator. Colors picked this way are often muddy or glaring;
+    /// see [`HSL::random_pleasant`] for colors that sit well together.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::RGB;
+    ///
+    /// let placeholder = RGB::random();
+    /// ```
+    pub fn random() -> RGB {
+        RGB::random_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`RGB::random`], but draws from the given random number generator, so that a
+    /// seeded generator always gives the same colors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::RGB;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let first = RGB::random_with(&mut StdRng::seed_from_u64(7));
+    /// let second = RGB::random_with(&mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> RGB {
+        RGB {
+            r: Ratio::from_u8(rng.gen()),
+            g: Ratio::from_u8(rng.gen()),
+            b: Ratio::from_u8(rng.gen()),
+        }
+    }
+}
+
+impl HSL {
+    /// Generates a color with a random hue at a fixed 65% saturation and 60% lightness, using
+    /// the thread-local random number generator. Keeping saturation and lightness in the
+    /// middle avoids the muddy and over-bright colors that [`RGB::random`] tends to give.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::HSL;
+    ///
+    /// let placeholder = HSL::random_pleasant();
+    ///
+    /// assert_eq!(placeholder.saturation_pct(), 65);
+    /// assert_eq!(placeholder.lightness_pct(), 60);
+    /// ```
+    pub fn random_pleasant() -> HSL {
+        HSL::random_pleasant_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`HSL::random_pleasant`], but draws from the given random number generator, so
+    /// that a seeded generator always gives the same colors.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::HSL;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let first = HSL::random_pleasant_with(&mut StdRng::seed_from_u64(7));
+    /// let second = HSL::random_pleasant_with(&mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn random_pleasant_with<R: Rng + ?Sized>(rng: &mut R) -> HSL {
+        hsl(rng.gen_range(0..360), 65, 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{HSL, RGB};
+
+    #[test]
+    fn random_colors_vary() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let colors: Vec<RGB> = (0..16).map(|_| RGB::random_with(&mut rng)).collect();
+
+        assert!(colors.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn pleasant_colors_only_vary_in_hue() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let colors: Vec<HSL> = (0..64)
+            .map(|_| HSL::random_pleasant_with(&mut rng))
+            .collect();
+
+        for color in &colors {
+            assert_eq!(color.saturation_pct(), 65);
+            assert_eq!(color.lightness_pct(), 60);
+        }
+
+        assert!(colors.windows(2).any(|pair| pair[0].h != pair[1].h));
+    }
+}
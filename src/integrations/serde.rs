@@ -1,18 +1,24 @@
-use serde::de::Error;
-use serde::{de::Visitor, Deserialize, Serialize, Serializer};
-use std::num::ParseIntError;
+use serde::de::{Error, Expected, Unexpected, Visitor};
+#[cfg(not(feature = "serde-struct"))]
+use serde::Deserialize;
 
+#[cfg(not(feature = "serde-struct"))]
 use crate::Color;
 
+#[cfg(not(feature = "serde-struct"))]
 macro_rules! impl_serialize {
     ($x:ident) => (
-        impl Serialize for crate::$x
+        impl serde::Serialize for crate::$x
         {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
-                S: Serializer,
+                S: serde::Serializer,
             {
-                serializer.serialize_str(&self.to_hex())
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&self.to_hex())
+                } else {
+                    serializer.serialize_u32(self.to_u32())
+                }
             }
         }
     );
@@ -23,47 +29,66 @@ macro_rules! impl_serialize {
     );
 }
 
+#[cfg(not(feature = "serde-struct"))]
 impl_serialize!(RGB, RGBA, HSL, HSLA);
 
+// Parses `N` pairs of hex digits, optionally preceded by a `#`, into that many channels,
+// describing what is wrong with `v` in terms of the `expected` format when it can't be parsed.
+fn parse_hex_channels<const N: usize, E: Error>(
+    v: &str,
+    expected: &dyn Expected,
+) -> Result<[u8; N], E> {
+    let digits = hex_digits(v);
+
+    if let Some(digit) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(Error::invalid_value(
+            Unexpected::Char(digit),
+            &"a hexadecimal digit",
+        ));
+    }
+
+    // Every remaining character is an ASCII digit, so bytes and characters line up.
+    if digits.len() != N * 2 {
+        return Err(Error::invalid_length(digits.len(), expected));
+    }
+
+    let mut channels = [0; N];
+
+    for (channel, pair) in channels.iter_mut().zip(digits.as_bytes().chunks(2)) {
+        *channel = hex_value(pair[0]) << 4 | hex_value(pair[1]);
+    }
+
+    Ok(channels)
+}
+
+// Strips the optional `#` in front of the digits of a hex color.
+fn hex_digits(v: &str) -> &str {
+    v.strip_prefix('#').unwrap_or(v)
+}
+
+fn hex_value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    }
+}
+
 struct RgbVisitor;
 impl<'de> Visitor<'de> for RgbVisitor {
     type Value = crate::RGB;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a string in the format of rrggbb")
+        formatter.write_str("a string in the format of #rrggbb")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
     {
-        let err = Err(serde::de::Error::invalid_value(
-            serde::de::Unexpected::Str(v),
-            &self,
-        ));
-        if v.len() != 7 {
-            return err;
-        }
+        let [r, g, b] = parse_hex_channels(v, &self)?;
 
-        if let Some('#') = v.chars().next() {
-            let values: Vec<u8> = match (1..v.len())
-                .step_by(2)
-                .map(|i| u8::from_str_radix(&v[i..i + 2], 16))
-                .collect::<Result<Vec<u8>, ParseIntError>>()
-            {
-                Ok(v) => v,
-                Err(_) => return err,
-            };
-            unsafe {
-                Ok(crate::rgb(
-                    *values.get_unchecked(0),
-                    *values.get_unchecked(1),
-                    *values.get_unchecked(2),
-                ))
-            }
-        } else {
-            err
-        }
+        Ok(crate::rgb(r, g, b))
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
@@ -78,41 +103,16 @@ impl<'de> Visitor<'de> for RgbaVisitor {
     type Value = crate::RGBA;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a string in the format of rrggbbaa")
+        formatter.write_str("a string in the format of #rrggbbaa")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
     {
-        let err = Err(serde::de::Error::invalid_value(
-            serde::de::Unexpected::Str(v),
-            &self,
-        ));
-        if v.len() != 9 {
-            return err;
-        }
+        let [r, g, b, a] = parse_hex_channels(v, &self)?;
 
-        if let Some('#') = v.chars().next() {
-            let values: Vec<u8> = match (1..v.len())
-                .step_by(2)
-                .map(|i| u8::from_str_radix(&v[i..i + 2], 16))
-                .collect::<Result<Vec<u8>, ParseIntError>>()
-            {
-                Ok(v) => v,
-                Err(_) => return err,
-            };
-            unsafe {
-                Ok(crate::rgba(
-                    *values.get_unchecked(0),
-                    *values.get_unchecked(1),
-                    *values.get_unchecked(2),
-                    *values.get_unchecked(3) as f32 / 255.,
-                ))
-            }
-        } else {
-            err
-        }
+        Ok(crate::rgba(r, g, b, a as f32 / 255.))
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
@@ -123,45 +123,556 @@ impl<'de> Visitor<'de> for RgbaVisitor {
     }
 }
 
+// Compact formats hold colors as the packed integer from `Color::to_u32`, while human
+// readable ones use the hex string.
+#[cfg(not(feature = "serde-struct"))]
 impl<'de> Deserialize<'de> for crate::RGB {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_string(RgbVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_string(RgbVisitor)
+        } else {
+            u32::deserialize(deserializer).map(crate::RGB::from_u32)
+        }
     }
 }
+#[cfg(not(feature = "serde-struct"))]
 impl<'de> Deserialize<'de> for crate::HSL {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer
-            .deserialize_string(RgbVisitor)
-            .map(|c| c.to_hsl())
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_string(HslVisitor {
+                hex: RgbVisitor,
+                convert: |c: crate::RGB| c.to_hsl(),
+            })
+        } else {
+            crate::RGB::deserialize(deserializer).map(|c| c.to_hsl())
+        }
     }
 }
+#[cfg(not(feature = "serde-struct"))]
 impl<'de> Deserialize<'de> for crate::RGBA {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_string(RgbaVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_string(RgbaVisitor)
+        } else {
+            u32::deserialize(deserializer).map(crate::RGBA::from_u32)
+        }
     }
 }
+#[cfg(not(feature = "serde-struct"))]
 impl<'de> Deserialize<'de> for crate::HSLA {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer
-            .deserialize_string(RgbaVisitor)
-            .map(|c| c.to_hsla())
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_string(HslVisitor {
+                hex: RgbaVisitor,
+                convert: |c: crate::RGBA| c.to_hsla(),
+            })
+        } else {
+            crate::RGBA::deserialize(deserializer).map(|c| c.to_hsla())
+        }
+    }
+}
+
+// Reads the hex strings of `hex`, converted by `convert`, as well as `hsl()` and `hsla()`
+// strings, which are parsed directly so that none of their channels go through RGB.
+#[cfg(not(feature = "serde-struct"))]
+struct HslVisitor<V, F> {
+    hex: V,
+    convert: F,
+}
+#[cfg(not(feature = "serde-struct"))]
+impl<'de, V, F, T> Visitor<'de> for HslVisitor<V, F>
+where
+    V: Visitor<'de>,
+    F: FnOnce(V::Value) -> T,
+    T: std::str::FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.hex.expecting(formatter)?;
+        formatter.write_str(", or an hsl() or hsla() string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let name = v.trim_start().get(..3);
+
+        if name.is_some_and(|name| name.eq_ignore_ascii_case("hsl")) {
+            v.parse()
+                .map_err(|_| Error::invalid_value(Unexpected::Str(v), &self))
+        } else {
+            self.hex.visit_str(v).map(self.convert)
+        }
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(&v)
+    }
+}
+
+// With the `serde-struct` feature, colors are held as their `r`, `g` and `b` channels
+// (plus `a` for alpha colors), each within `0-255`, in every format.
+#[cfg(feature = "serde-struct")]
+mod structured {
+    use std::marker::PhantomData;
+
+    use serde::ser::SerializeStruct;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::flexible::FlexibleVisitor;
+    use crate::{Color, HSL, HSLA, RGB, RGBA};
+
+    impl Serialize for RGB {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("RGB", 3)?;
+            state.serialize_field("r", &self.r.as_u8())?;
+            state.serialize_field("g", &self.g.as_u8())?;
+            state.serialize_field("b", &self.b.as_u8())?;
+            state.end()
+        }
+    }
+
+    impl Serialize for RGBA {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("RGBA", 4)?;
+            state.serialize_field("r", &self.r.as_u8())?;
+            state.serialize_field("g", &self.g.as_u8())?;
+            state.serialize_field("b", &self.b.as_u8())?;
+            state.serialize_field("a", &self.a.as_u8())?;
+            state.end()
+        }
+    }
+
+    impl Serialize for HSL {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.to_rgb().serialize(serializer)
+        }
+    }
+
+    impl Serialize for HSLA {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.to_rgba().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RGB {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("RGB", &["r", "g", "b"], FlexibleVisitor(PhantomData))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RGBA {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_struct(
+                "RGBA",
+                &["r", "g", "b", "a"],
+                FlexibleVisitor(PhantomData),
+            )
+        }
+    }
+
+    impl<'de> Deserialize<'de> for HSL {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            RGB::deserialize(deserializer).map(|c| c.to_hsl())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for HSLA {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            RGBA::deserialize(deserializer).map(|c| c.to_hsla())
+        }
+    }
+}
+
+/// Serializes an `RGBA` as `#rrggbb` when it is fully opaque, and as `#rrggbbaa` otherwise.
+/// Deserializing accepts either form.
+///
+/// Use it with `#[serde(with = "farver::serde_opaque_hex")]` for output that tools without
+/// support for 8-digit hex colors can read.
+///
+/// # Example
+/// ```
+/// use farver::{rgba, RGBA};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde_opaque_hex")]
+///     accent: RGBA,
+/// }
+///
+/// let theme = Theme { accent: rgba(250, 128, 114, 1.0) };
+///
+/// assert_eq!(serde_json::to_string(&theme).unwrap(), r##"{"accent":"#fa8072"}"##);
+/// ```
+pub mod opaque_hex {
+    use serde::{Deserializer, Serializer};
+
+    use super::{RgbVisitor, RgbaVisitor};
+    use crate::{Color, RGBA};
+
+    pub fn serialize<S>(color: &RGBA, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if color.a.as_u8() == u8::MAX {
+            serializer.serialize_str(&color.to_rgb().to_hex())
+        } else {
+            serializer.serialize_str(&color.to_hex())
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RGBA, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(OpaqueHexVisitor)
+    }
+
+    struct OpaqueHexVisitor;
+    impl<'de> serde::de::Visitor<'de> for OpaqueHexVisitor {
+        type Value = RGBA;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a string in the format of #rrggbb or #rrggbbaa")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            if super::hex_digits(v).len() == 6 {
+                RgbVisitor.visit_str(v).map(|c| c.to_rgba())
+            } else {
+                RgbaVisitor.visit_str(v)
+            }
+        }
+    }
+}
+
+/// Deserializes an `RGB` or `RGBA` from either its hex string form, an object with numeric
+/// `r`, `g` and `b` fields (plus an `a` field for `RGBA`), or an array of 3 (or 4 for `RGBA`)
+/// numbers, with every channel within `0-255`. An `RGBA` without an alpha channel, or in the
+/// 6-digit hex form, is fully opaque. Serializing uses the hex string form.
+///
+/// Use it with `#[serde(with = "farver::serde_flexible")]` to accept any of these representations,
+/// such as while migrating configuration files from one to another, or when reading tuples
+/// written by other tools.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, RGB};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde_flexible")]
+///     background: RGB,
+///     #[serde(with = "farver::serde_flexible")]
+///     foreground: RGB,
+///     #[serde(with = "farver::serde_flexible")]
+///     border: RGB,
+/// }
+///
+/// let theme: Theme = serde_json::from_str(
+///     r##"{
+///         "background": "#fa8072",
+///         "foreground": { "r": 250, "g": 128, "b": 114 },
+///         "border": [250, 128, 114]
+///     }"##,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(theme.background, rgb(250, 128, 114));
+/// assert_eq!(theme.foreground, rgb(250, 128, 114));
+/// assert_eq!(theme.border, rgb(250, 128, 114));
+/// ```
+pub mod flexible {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use super::{RgbVisitor, RgbaVisitor};
+    use crate::{rgb, Color, Ratio, RGB, RGBA};
+
+    /// The color types supported by [`serde_flexible`](crate::serde_flexible).
+    pub trait FlexibleColor: Color {
+        #[doc(hidden)]
+        const HAS_ALPHA: bool;
+
+        #[doc(hidden)]
+        fn from_hex<E: Error>(v: &str) -> Result<Self, E>;
+
+        #[doc(hidden)]
+        fn from_channels(r: u8, g: u8, b: u8, a: Option<u8>) -> Self;
+    }
+
+    impl FlexibleColor for RGB {
+        const HAS_ALPHA: bool = false;
+
+        fn from_hex<E: Error>(v: &str) -> Result<Self, E> {
+            RgbVisitor.visit_str(v)
+        }
+
+        fn from_channels(r: u8, g: u8, b: u8, _: Option<u8>) -> Self {
+            rgb(r, g, b)
+        }
+    }
+
+    impl FlexibleColor for RGBA {
+        const HAS_ALPHA: bool = true;
+
+        fn from_hex<E: Error>(v: &str) -> Result<Self, E> {
+            if super::hex_digits(v).len() == 6 {
+                RgbVisitor.visit_str(v).map(|c| c.to_rgba())
+            } else {
+                RgbaVisitor.visit_str(v)
+            }
+        }
+
+        fn from_channels(r: u8, g: u8, b: u8, a: Option<u8>) -> Self {
+            RGBA {
+                r: Ratio::from_u8(r),
+                g: Ratio::from_u8(g),
+                b: Ratio::from_u8(b),
+                a: Ratio::from_u8(a.unwrap_or(u8::MAX)),
+            }
+        }
+    }
+
+    pub fn serialize<T, S>(color: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: FlexibleColor + Copy,
+        S: Serializer,
+    {
+        serializer.serialize_str(&color.to_hex())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FlexibleColor,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FlexibleVisitor(PhantomData))
+    }
+
+    pub(super) struct FlexibleVisitor<T>(pub(super) PhantomData<T>);
+    impl<'de, T: FlexibleColor> Visitor<'de> for FlexibleVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            if T::HAS_ALPHA {
+                formatter.write_str(
+                    "a hex color string, an object with r, g, b and a fields, or an array of 3 or 4 channels",
+                )
+            } else {
+                formatter.write_str(
+                    "a hex color string, an object with r, g and b fields, or an array of 3 channels",
+                )
+            }
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            T::from_hex(v)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let r = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(0, &self))?;
+            let g = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(1, &self))?;
+            let b = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(2, &self))?;
+            let a = if T::HAS_ALPHA {
+                seq.next_element()?
+            } else {
+                None
+            };
+
+            let len = if T::HAS_ALPHA { 4 } else { 3 };
+
+            if seq.next_element::<IgnoredAny>()?.is_some() {
+                return Err(Error::invalid_length(len + 1, &self));
+            }
+
+            Ok(T::from_channels(r, g, b, a))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            const FIELDS: &[&str] = &["r", "g", "b", "a"];
+
+            let mut channels: [Option<u8>; 4] = [None; 4];
+
+            while let Some(key) = map.next_key::<String>()? {
+                let index = match key.as_str() {
+                    "r" => 0,
+                    "g" => 1,
+                    "b" => 2,
+                    "a" if T::HAS_ALPHA => 3,
+                    _ => {
+                        let fields = if T::HAS_ALPHA { FIELDS } else { &FIELDS[..3] };
+
+                        return Err(Error::unknown_field(&key, fields));
+                    }
+                };
+
+                if channels[index].is_some() {
+                    return Err(Error::duplicate_field(FIELDS[index]));
+                }
+
+                channels[index] = Some(map.next_value()?);
+            }
+
+            let [r, g, b, a] = channels;
+
+            Ok(T::from_channels(
+                r.ok_or_else(|| Error::missing_field("r"))?,
+                g.ok_or_else(|| Error::missing_field("g"))?,
+                b.ok_or_else(|| Error::missing_field("b"))?,
+                a,
+            ))
+        }
+    }
+}
+
+/// Serializes an `HSL` or `HSLA` as its CSS functional notation, such as `hsl(6, 93%, 71%)` or
+/// `hsla(6, 93%, 71%, 0.50)`, instead of as hex. Deserializing accepts any CSS color string, and
+/// `hsl()` and `hsla()` strings keep their hue, saturation and lightness exactly rather than
+/// going through `RGB`. Both the comma-separated syntax and the space-separated one, such as
+/// `hsl(6deg 93% 71% / 0.5)`, are accepted.
+///
+/// Use it with `#[serde(with = "farver::serde_hsl")]` for colors that are authored in HSL and
+/// should round-trip without drifting.
+///
+/// # Example
+/// ```
+/// use farver::{hsl, HSL};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "farver::serde_hsl")]
+///     accent: HSL,
+/// }
+///
+/// let theme = Theme { accent: hsl(6, 93, 71) };
+/// let json = serde_json::to_string(&theme).unwrap();
+///
+/// assert_eq!(json, r#"{"accent":"hsl(6, 93%, 71%)"}"#);
+/// assert_eq!(serde_json::from_str::<Theme>(&json).unwrap().accent, hsl(6, 93, 71));
+/// ```
+pub mod hsl {
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use serde::de::{Error, Unexpected, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use crate::{Color, HSL, HSLA};
+
+    /// The color types supported by [`serde_hsl`](crate::serde_hsl).
+    pub trait HslColor: Color + FromStr {}
+
+    impl HslColor for HSL {}
+    impl HslColor for HSLA {}
+
+    pub fn serialize<T, S>(color: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: HslColor + Copy,
+        S: Serializer,
+    {
+        serializer.serialize_str(&color.to_css())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: HslColor,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(HslVisitor(PhantomData))
+    }
+
+    struct HslVisitor<T>(PhantomData<T>);
+    impl<'de, T: HslColor> Visitor<'de> for HslVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a CSS color string, such as hsl(6, 93%, 71%)")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            v.parse()
+                .map_err(|_| Error::invalid_value(Unexpected::Str(v), &self))
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::Color;
+
+    #[cfg(not(feature = "serde-struct"))]
     #[test]
     fn no_alpha_json_deserializing() {
         let input_str = r##"{"color": "#010203"}"##;
@@ -178,7 +689,7 @@ mod tests {
         )
     }
 
-    #[cfg(test)]
+    #[cfg(not(feature = "serde-struct"))]
     #[test]
     fn alpha_json_deserializing() {
         let input_str = r##"{"color": "#010203FF"}"##;
@@ -194,4 +705,396 @@ mod tests {
             }
         )
     }
+
+    #[cfg(not(feature = "serde-struct"))]
+    #[test]
+    fn hex_json_deserializing_without_prefix() {
+        let salmon = crate::rgba(250, 128, 114, 0.5);
+
+        for (with, without) in [
+            (r##""#fa8072""##, r#""fa8072""#),
+            (r##""#FA8072""##, r#""FA8072""#),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<crate::RGB>(without).unwrap(),
+                serde_json::from_str::<crate::RGB>(with).unwrap()
+            );
+        }
+
+        assert_eq!(
+            serde_json::from_str::<crate::RGBA>(r#""fa807280""#).unwrap(),
+            salmon
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::HSL>(r#""fa8072""#).unwrap(),
+            salmon.to_rgb().to_hsl()
+        );
+        assert_eq!(
+            serde_json::from_str::<OpaqueHex>(r#"{"color": "fa8072"}"#).unwrap(),
+            OpaqueHex {
+                color: crate::rgba(250, 128, 114, 1.0)
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<Flexible>(r#"{"rgb": "fa8072", "rgba": "fa8072"}"#).unwrap(),
+            Flexible {
+                rgb: salmon.to_rgb(),
+                rgba: crate::rgba(250, 128, 114, 1.0),
+            }
+        );
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct OpaqueHex {
+        #[serde(with = "crate::serde_opaque_hex")]
+        color: crate::RGBA,
+    }
+
+    #[test]
+    fn opaque_hex_json_serializing() {
+        let opaque = OpaqueHex {
+            color: crate::rgba(250, 128, 114, 1.0),
+        };
+        let translucent = OpaqueHex {
+            color: crate::rgba(250, 128, 114, 0.5),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&opaque).unwrap(),
+            r##"{"color":"#fa8072"}"##
+        );
+        assert_eq!(
+            serde_json::to_string(&translucent).unwrap(),
+            r##"{"color":"#fa807280"}"##
+        );
+        #[cfg(not(feature = "serde-struct"))]
+        assert_eq!(
+            serde_json::to_string(&crate::rgba(250, 128, 114, 1.0)).unwrap(),
+            r##""#fa8072ff""##
+        );
+    }
+
+    #[test]
+    fn opaque_hex_json_deserializing() {
+        let opaque: OpaqueHex = serde_json::from_str(r##"{"color": "#fa8072"}"##).unwrap();
+        let translucent: OpaqueHex = serde_json::from_str(r##"{"color": "#fa807280"}"##).unwrap();
+
+        assert_eq!(opaque.color, crate::rgba(250, 128, 114, 1.0));
+        assert_eq!(translucent.color, crate::rgba(250, 128, 114, 0.5));
+        assert!(serde_json::from_str::<OpaqueHex>(r##"{"color": "#fa80"}"##).is_err());
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Flexible {
+        #[serde(with = "crate::serde_flexible")]
+        rgb: crate::RGB,
+        #[serde(with = "crate::serde_flexible")]
+        rgba: crate::RGBA,
+    }
+
+    #[test]
+    fn flexible_json_deserializing_hex() {
+        let t: Flexible =
+            serde_json::from_str(r##"{"rgb": "#010203", "rgba": "#01020380"}"##).unwrap();
+
+        assert_eq!(
+            t,
+            Flexible {
+                rgb: crate::rgb(1, 2, 3),
+                rgba: crate::rgba(1, 2, 3, 0.5),
+            }
+        );
+    }
+
+    #[test]
+    fn flexible_json_deserializing_struct() {
+        let t: Flexible = serde_json::from_str(
+            r##"{"rgb": {"r": 1, "g": 2, "b": 3}, "rgba": {"b": 3, "g": 2, "r": 1, "a": 128}}"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            t,
+            Flexible {
+                rgb: crate::rgb(1, 2, 3),
+                rgba: crate::rgba(1, 2, 3, 0.5),
+            }
+        );
+
+        let t: Flexible =
+            serde_json::from_str(r##"{"rgb": "#010203", "rgba": {"r": 1, "g": 2, "b": 3}}"##)
+                .unwrap();
+
+        assert_eq!(t.rgba, crate::rgba(1, 2, 3, 1.0));
+    }
+
+    #[test]
+    fn flexible_json_rejects_invalid_structs() {
+        let invalid = [
+            r##"{"rgb": {"r": 256, "g": 2, "b": 3}, "rgba": "#01020380"}"##,
+            r##"{"rgb": {"r": -1, "g": 2, "b": 3}, "rgba": "#01020380"}"##,
+            r##"{"rgb": {"r": 1, "g": 2}, "rgba": "#01020380"}"##,
+            r##"{"rgb": {"r": 1, "g": 2, "b": 3, "a": 4}, "rgba": "#01020380"}"##,
+            r##"{"rgb": {"r": 1, "r": 1, "g": 2, "b": 3}, "rgba": "#01020380"}"##,
+            r##"{"rgb": "#010203", "rgba": {"r": 1, "g": 2, "b": 3, "a": 256}}"##,
+        ];
+
+        for input in invalid {
+            assert!(
+                serde_json::from_str::<Flexible>(input).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn flexible_json_deserializing_array() {
+        let t: Flexible =
+            serde_json::from_str(r##"{"rgb": [1, 2, 3], "rgba": [1, 2, 3, 128]}"##).unwrap();
+
+        assert_eq!(
+            t,
+            Flexible {
+                rgb: crate::rgb(1, 2, 3),
+                rgba: crate::rgba(1, 2, 3, 0.5),
+            }
+        );
+
+        let t: Flexible =
+            serde_json::from_str(r##"{"rgb": [1, 2, 3], "rgba": [1, 2, 3]}"##).unwrap();
+
+        assert_eq!(t.rgba, crate::rgba(1, 2, 3, 1.0));
+    }
+
+    #[test]
+    fn flexible_json_rejects_invalid_arrays() {
+        let invalid = [
+            r##"{"rgb": [1, 2], "rgba": [1, 2, 3]}"##,
+            r##"{"rgb": [1, 2, 3, 4], "rgba": [1, 2, 3]}"##,
+            r##"{"rgb": [1, 2, 256], "rgba": [1, 2, 3]}"##,
+            r##"{"rgb": [1, 2, -3], "rgba": [1, 2, 3]}"##,
+            r##"{"rgb": [1, 2, 3], "rgba": [1, 2, 3, 4, 5]}"##,
+            r##"{"rgb": [1, 2, 3], "rgba": []}"##,
+        ];
+
+        for input in invalid {
+            assert!(
+                serde_json::from_str::<Flexible>(input).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Hsl {
+        #[serde(with = "crate::serde_hsl")]
+        hsl: crate::HSL,
+        #[serde(with = "crate::serde_hsl")]
+        hsla: crate::HSLA,
+    }
+
+    #[test]
+    fn hsl_json_round_trips_exactly() {
+        let t = Hsl {
+            hsl: crate::hsl(200, 40, 70),
+            hsla: crate::hsla(200, 40, 70, 0.5),
+        };
+        let json = serde_json::to_string(&t).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"hsl":"hsl(200, 40%, 70%)","hsla":"hsla(200, 40%, 70%, 0.50)"}"#
+        );
+        assert_eq!(serde_json::from_str::<Hsl>(&json).unwrap(), t);
+    }
+
+    #[test]
+    fn hsl_json_rejects_invalid_strings() {
+        let invalid = [
+            r#"{"hsl": "hsl(200, 40%)", "hsla": "hsla(200, 40%, 70%, 0.5)"}"#,
+            r#"{"hsl": "hsl(200, 140%, 70%)", "hsla": "hsla(200, 40%, 70%, 0.5)"}"#,
+            r#"{"hsl": [200, 40, 70], "hsla": "hsla(200, 40%, 70%, 0.5)"}"#,
+        ];
+
+        for input in invalid {
+            assert!(serde_json::from_str::<Hsl>(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn hsl_json_accepts_space_separated_syntax() {
+        let legacy = r#"{"hsl": "hsl(6, 93%, 71%)", "hsla": "hsla(6, 93%, 71%, 0.5)"}"#;
+        let modern = r#"{"hsl": "hsl(6deg 93% 71%)", "hsla": "hsla(6 93% 71% / 0.5)"}"#;
+
+        assert_eq!(
+            serde_json::from_str::<Hsl>(modern).unwrap(),
+            serde_json::from_str::<Hsl>(legacy).unwrap()
+        );
+    }
+
+    #[cfg(not(feature = "serde-struct"))]
+    #[test]
+    fn hsl_json_deserializing_keeps_every_channel() {
+        let color = crate::hsla(200, 40, 70, 0.33);
+
+        assert_eq!(
+            serde_json::from_str::<crate::HSLA>(r#""hsla(200, 40%, 70%, 0.33)""#).unwrap(),
+            color
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::HSLA>(r#""HSL(200 40% 70% / 33%)""#).unwrap(),
+            color
+        );
+        assert_ne!(
+            serde_json::from_str::<crate::HSLA>(&serde_json::to_string(&color).unwrap()).unwrap(),
+            color
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::HSL>(r#""hsl(200, 40%, 70%)""#).unwrap(),
+            crate::hsl(200, 40, 70)
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::HSL>(r##""#fa8072""##).unwrap(),
+            crate::rgb(250, 128, 114).to_hsl()
+        );
+
+        for invalid in [r#""hsla(200, 40%)""#, r#""hsl(200, 140%, 70%)""#, r#""hs""#] {
+            assert!(
+                serde_json::from_str::<crate::HSLA>(invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[cfg(not(feature = "serde-struct"))]
+    #[test]
+    fn hex_json_rejects_malformed_strings() {
+        let invalid = [
+            r##""#0102""##,
+            r##""0102""##,
+            r###""##010203""###,
+            r##""#01020g""##,
+            r##""01020g""##,
+            r##""#0é000""##,
+            r##""#0102030é""##,
+        ];
+
+        for input in invalid {
+            assert!(
+                serde_json::from_str::<crate::RGB>(input).is_err(),
+                "{}",
+                input
+            );
+            assert!(
+                serde_json::from_str::<crate::RGBA>(input).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[cfg(not(feature = "serde-struct"))]
+    #[test]
+    fn compact_formats_use_packed_integers() {
+        let salmon = crate::rgba(250, 128, 114, 0.5);
+
+        let json = serde_json::to_string(&salmon).unwrap();
+        let bytes = bincode::serialize(&salmon).unwrap();
+
+        assert_eq!(json, r##""#fa807280""##);
+        assert_eq!(bytes, 0xfa807280u32.to_le_bytes());
+        assert_eq!(serde_json::from_str::<crate::RGBA>(&json).unwrap(), salmon);
+        assert_eq!(bincode::deserialize::<crate::RGBA>(&bytes).unwrap(), salmon);
+
+        let rgb = salmon.to_rgb();
+        let bytes = bincode::serialize(&rgb).unwrap();
+
+        assert_eq!(bytes, 0xfa8072u32.to_le_bytes());
+        assert_eq!(bincode::deserialize::<crate::RGB>(&bytes).unwrap(), rgb);
+        assert_eq!(
+            bincode::deserialize::<crate::HSL>(&bincode::serialize(&rgb.to_hsl()).unwrap())
+                .unwrap(),
+            rgb.to_hsl().to_rgb().to_hsl()
+        );
+        assert_eq!(
+            bincode::deserialize::<crate::HSLA>(&bincode::serialize(&salmon.to_hsla()).unwrap())
+                .unwrap(),
+            salmon.to_hsla().to_rgba().to_hsla()
+        );
+    }
+
+    #[cfg(not(feature = "serde-struct"))]
+    #[test]
+    fn hex_json_errors_describe_the_problem() {
+        let error = |input: &str| {
+            serde_json::from_str::<crate::RGB>(input)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(r##""#0102""##),
+            "invalid length 4, expected a string in the format of #rrggbb at line 1 column 7"
+        );
+        assert_eq!(
+            error(r##""#0102030""##),
+            "invalid length 7, expected a string in the format of #rrggbb at line 1 column 10"
+        );
+        assert_eq!(
+            error(r###""##010203""###),
+            "invalid value: character `#`, expected a hexadecimal digit at line 1 column 10"
+        );
+        assert_eq!(
+            error(r##""0102""##),
+            "invalid length 4, expected a string in the format of #rrggbb at line 1 column 6"
+        );
+        assert_eq!(
+            error(r##""#01020g""##),
+            "invalid value: character `g`, expected a hexadecimal digit at line 1 column 9"
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::RGBA>(r##""#010203""##)
+                .unwrap_err()
+                .to_string(),
+            "invalid length 6, expected a string in the format of #rrggbbaa at line 1 column 9"
+        );
+    }
+
+    #[cfg(feature = "serde-struct")]
+    #[test]
+    fn struct_feature_uses_channels() {
+        let salmon = crate::rgba(250, 128, 114, 0.5);
+
+        let json = serde_json::to_string(&salmon).unwrap();
+        let bytes = bincode::serialize(&salmon).unwrap();
+
+        assert_eq!(json, r#"{"r":250,"g":128,"b":114,"a":128}"#);
+        assert_eq!(bytes, [250, 128, 114, 128]);
+        assert_eq!(serde_json::from_str::<crate::RGBA>(&json).unwrap(), salmon);
+        assert_eq!(bincode::deserialize::<crate::RGBA>(&bytes).unwrap(), salmon);
+
+        let rgb = salmon.to_rgb();
+
+        assert_eq!(
+            serde_json::to_string(&rgb).unwrap(),
+            r#"{"r":250,"g":128,"b":114}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&rgb.to_hsl()).unwrap(),
+            serde_json::to_string(&rgb.to_hsl().to_rgb()).unwrap()
+        );
+        assert_eq!(
+            bincode::deserialize::<crate::RGB>(&bincode::serialize(&rgb).unwrap()).unwrap(),
+            rgb
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::HSLA>(&json).unwrap(),
+            salmon.to_hsla()
+        );
+        assert!(serde_json::from_str::<crate::RGB>(r#"{"r":250,"g":128}"#).is_err());
+        assert!(serde_json::from_str::<crate::RGB>(r##""#fa8072""##).is_err());
+    }
 }
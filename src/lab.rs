@@ -0,0 +1,413 @@
+use crate::math::{atan2, cbrt, cos, exp, powf, sin, sqrt};
+use crate::{Ratio, RGB};
+
+// The reference white of the sRGB color space (D65), in CIE XYZ.
+const WHITE_X: f32 = 0.950_47;
+const WHITE_Y: f32 = 1.0;
+const WHITE_Z: f32 = 1.088_83;
+
+// Converts a gamma encoded sRGB channel into linear light.
+pub(crate) fn to_linear(channel: f32) -> f32 {
+    if channel <= 0.040_45 {
+        channel / 12.92
+    } else {
+        powf((channel + 0.055) / 1.055, 2.4)
+    }
+}
+
+// Converts a linear light channel into a gamma encoded sRGB channel.
+pub(crate) fn from_linear(channel: f32) -> f32 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * powf(channel, 1.0 / 2.4) - 0.055
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+// A color in the CIE L*a*b* color space, using a D65 reference white.
+// `l` ranges between `0-100`, while `a` and `b` are roughly within `-128-128`.
+pub(crate) struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Lab {
+    pub fn from_rgb(rgb: RGB) -> Self {
+        let r = to_linear(rgb.r.as_f32());
+        let g = to_linear(rgb.g.as_f32());
+        let b = to_linear(rgb.b.as_f32());
+
+        let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+        let fx = lab_f(x / WHITE_X);
+        let fy = lab_f(y / WHITE_Y);
+        let fz = lab_f(z / WHITE_Z);
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    // Converts `self` back into sRGB, clamping colors that fall outside of its gamut.
+    pub fn to_rgb(self) -> RGB {
+        let [r, g, b] = self.to_linear_rgb();
+        let channel = |linear: f32| Ratio::from_f32(from_linear(linear.clamp(0.0, 1.0)));
+
+        RGB {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+        }
+    }
+
+    // Reduces the chroma of `self` until it fits within the sRGB gamut, keeping
+    // its lightness and hue.
+    pub fn clamp_chroma(self) -> Self {
+        let scaled = |factor: f32| Lab {
+            a: self.a * factor,
+            b: self.b * factor,
+            ..self
+        };
+
+        if scaled(1.0).in_gamut() {
+            return self;
+        }
+
+        // Binary search for the largest in gamut chroma. A grey is always in gamut.
+        let (mut low, mut high) = (0.0, 1.0);
+
+        for _ in 0..16 {
+            let middle = (low + high) / 2.0;
+
+            if scaled(middle).in_gamut() {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        scaled(low)
+    }
+
+    fn in_gamut(self) -> bool {
+        // Allow for the rounding errors of the conversion itself.
+        self.to_linear_rgb()
+            .iter()
+            .all(|channel| (-0.000_01..=1.000_01).contains(channel))
+    }
+
+    fn to_linear_rgb(self) -> [f32; 3] {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        let x = WHITE_X * lab_f_inverse(fx);
+        let y = WHITE_Y * lab_f_inverse(fy);
+        let z = WHITE_Z * lab_f_inverse(fz);
+
+        [
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+        ]
+    }
+
+    // The CIEDE2000 color difference between `self` and `other`, following
+    // "The CIEDE2000 Color-Difference Formula" by Sharma, Wu and Dalal.
+    pub fn delta_e(self, other: Lab) -> f32 {
+        let Lab {
+            l: l_1,
+            a: a_1,
+            b: b_1,
+        } = self;
+        let Lab {
+            l: l_2,
+            a: a_2,
+            b: b_2,
+        } = other;
+
+        // Rescale the a* axis to compensate for the poor perceptual uniformity
+        // of low chroma colors, and find the chroma and hue of each color.
+        let c_bar = (sqrt(a_1 * a_1 + b_1 * b_1) + sqrt(a_2 * a_2 + b_2 * b_2)) / 2.0;
+        let g = 0.5 * (1.0 - chroma_weight(c_bar));
+
+        let a_1 = (1.0 + g) * a_1;
+        let a_2 = (1.0 + g) * a_2;
+
+        let c_1 = sqrt(a_1 * a_1 + b_1 * b_1);
+        let c_2 = sqrt(a_2 * a_2 + b_2 * b_2);
+
+        let h_1 = hue_degrees(b_1, a_1);
+        let h_2 = hue_degrees(b_2, a_2);
+
+        // Differences in lightness, chroma and hue. The hue difference is
+        // undefined (and zero) when either color is achromatic.
+        let delta_l = l_2 - l_1;
+        let delta_c = c_2 - c_1;
+
+        let delta_h = if c_1 * c_2 == 0.0 {
+            0.0
+        } else if (h_2 - h_1).abs() <= 180.0 {
+            h_2 - h_1
+        } else if h_2 - h_1 > 180.0 {
+            h_2 - h_1 - 360.0
+        } else {
+            h_2 - h_1 + 360.0
+        };
+        let delta_h = 2.0 * sqrt(c_1 * c_2) * sin((delta_h / 2.0).to_radians());
+
+        // Averages of lightness, chroma and hue.
+        let l_bar = (l_1 + l_2) / 2.0;
+        let c_bar = (c_1 + c_2) / 2.0;
+
+        let h_bar = if c_1 * c_2 == 0.0 {
+            h_1 + h_2
+        } else if (h_1 - h_2).abs() <= 180.0 {
+            (h_1 + h_2) / 2.0
+        } else if h_1 + h_2 < 360.0 {
+            (h_1 + h_2 + 360.0) / 2.0
+        } else {
+            (h_1 + h_2 - 360.0) / 2.0
+        };
+
+        // Weighting functions for each of the differences, plus a rotation
+        // term correcting for the interaction of chroma and hue in the blues.
+        let t = 1.0 - 0.17 * cos((h_bar - 30.0).to_radians())
+            + 0.24 * cos((2.0 * h_bar).to_radians())
+            + 0.32 * cos((3.0 * h_bar + 6.0).to_radians())
+            - 0.20 * cos((4.0 * h_bar - 63.0).to_radians());
+
+        let delta_theta = 30.0 * exp(-powf((h_bar - 275.0) / 25.0, 2.0));
+        let r_c = 2.0 * chroma_weight(c_bar);
+
+        let s_l = 1.0 + (0.015 * powf(l_bar - 50.0, 2.0)) / sqrt(20.0 + powf(l_bar - 50.0, 2.0));
+        let s_c = 1.0 + 0.045 * c_bar;
+        let s_h = 1.0 + 0.015 * c_bar * t;
+        let r_t = -sin((2.0 * delta_theta).to_radians()) * r_c;
+
+        let l = delta_l / s_l;
+        let c = delta_c / s_c;
+        let h = delta_h / s_h;
+
+        sqrt(l * l + c * c + h * h + r_t * c * h)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+// A color in Björn Ottosson's Oklab color space, which is designed so that
+// interpolating between two colors looks perceptually even. `l` ranges between
+// `0-1`, while `a` and `b` are roughly within `-0.4-0.4`.
+pub(crate) struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Oklab {
+    pub fn from_rgb(rgb: RGB) -> Self {
+        let r = to_linear(rgb.r.as_f32());
+        let g = to_linear(rgb.g.as_f32());
+        let b = to_linear(rgb.b.as_f32());
+
+        // Approximate the cone responses (LMS), then apply a cube root to make them
+        // perceptually uniform.
+        let l = cbrt(0.412_221_47 * r + 0.536_332_54 * g + 0.051_445_995 * b);
+        let m = cbrt(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = cbrt(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    // Converts `self` back into sRGB, clamping colors that fall outside of its gamut.
+    pub fn to_rgb(self) -> RGB {
+        let Oklab { l, a, b } = self;
+
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        let channel = |linear: f32| Ratio::from_f32(from_linear(linear.clamp(0.0, 1.0)));
+
+        RGB {
+            r: channel(4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s),
+            g: channel(-1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s),
+            b: channel(-0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s),
+        }
+    }
+}
+
+// The nonlinear function used to convert from CIE XYZ to CIE L*a*b*.
+fn lab_f(t: f32) -> f32 {
+    let delta: f32 = 6.0 / 29.0;
+
+    if t > delta * delta * delta {
+        cbrt(t)
+    } else {
+        t / (3.0 * delta * delta) + 4.0 / 29.0
+    }
+}
+
+// The inverse of `lab_f`.
+fn lab_f_inverse(t: f32) -> f32 {
+    let delta: f32 = 6.0 / 29.0;
+
+    if t > delta {
+        t * t * t
+    } else {
+        3.0 * delta * delta * (t - 4.0 / 29.0)
+    }
+}
+
+// A weight approaching 1 for highly chromatic colors, used by CIEDE2000.
+fn chroma_weight(chroma: f32) -> f32 {
+    let c_7 = powf(chroma, 7.0);
+
+    sqrt(c_7 / (c_7 + powf(25.0, 7.0)))
+}
+
+// The hue angle of a point on the a*b* plane, in degrees within `0-360`.
+fn hue_degrees(b: f32, a: f32) -> f32 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+
+    let hue = atan2(b, a).to_degrees();
+
+    if hue < 0.0 {
+        hue + 360.0
+    } else {
+        hue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lab, Oklab};
+    use crate::rgb;
+
+    fn assert_close(lhs: f32, rhs: f32) {
+        assert!((lhs - rhs).abs() < 0.01, "lhs: {}, rhs: {}", lhs, rhs);
+    }
+
+    #[test]
+    fn can_convert_rgb_to_lab() {
+        let white = Lab::from_rgb(rgb(255, 255, 255));
+        let red = Lab::from_rgb(rgb(255, 0, 0));
+
+        assert_close(white.l, 100.0);
+        assert_close(white.a, 0.0);
+        assert_close(white.b, 0.0);
+
+        assert_close(red.l, 53.24);
+        assert_close(red.a, 80.09);
+        assert_close(red.b, 67.20);
+    }
+
+    #[test]
+    fn round_trips_lab() {
+        for color in [
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+            rgb(250, 128, 114),
+            rgb(23, 98, 119),
+            rgb(0, 0, 255),
+            rgb(3, 2, 1),
+        ] {
+            assert_eq!(Lab::from_rgb(color).to_rgb(), color);
+        }
+    }
+
+    #[test]
+    fn clamps_chroma_into_gamut() {
+        let salmon = Lab::from_rgb(rgb(250, 128, 114));
+        assert_eq!(salmon.clamp_chroma(), salmon);
+
+        let bright = Lab { l: 95.0, ..salmon }.clamp_chroma();
+        assert_eq!(bright.l, 95.0);
+        assert!(bright.a.abs() < salmon.a.abs());
+        assert!((bright.a / bright.b - salmon.a / salmon.b).abs() < 0.001);
+        assert!(bright.in_gamut());
+
+        assert_eq!(
+            Lab { l: 0.0, ..salmon }.clamp_chroma().to_rgb(),
+            rgb(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_oklab() {
+        let white = Oklab::from_rgb(rgb(255, 255, 255));
+        let red = Oklab::from_rgb(rgb(255, 0, 0));
+
+        assert_close(white.l, 1.0);
+        assert_close(white.a, 0.0);
+        assert_close(white.b, 0.0);
+
+        assert_close(red.l, 0.628);
+        assert_close(red.a, 0.225);
+        assert_close(red.b, 0.126);
+    }
+
+    #[test]
+    fn round_trips_oklab() {
+        for color in [
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+            rgb(250, 128, 114),
+            rgb(23, 98, 119),
+            rgb(127, 255, 0),
+        ] {
+            assert_eq!(Oklab::from_rgb(color).to_rgb(), color);
+        }
+    }
+
+    #[test]
+    fn can_compute_delta_e() {
+        // Test pairs from Sharma, Wu and Dalal's supplementary data.
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, -1.0, 2.0), (50.0, 0.0, 0.0), 2.3669),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                (22.7233, 20.0904, -46.694),
+                (23.0331, 14.973, -42.5619),
+                2.0373,
+            ),
+        ];
+
+        for ((l_1, a_1, b_1), (l_2, a_2, b_2), expected) in pairs {
+            let lhs = Lab {
+                l: l_1,
+                a: a_1,
+                b: b_1,
+            };
+            let rhs = Lab {
+                l: l_2,
+                a: a_2,
+                b: b_2,
+            };
+
+            assert_close(lhs.delta_e(rhs), expected);
+            assert_close(rhs.delta_e(lhs), expected);
+        }
+    }
+}
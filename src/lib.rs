@@ -1,17 +1,61 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![forbid(unsafe_code)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("farver requires either the `std` or the `libm` feature to be enabled");
+
+#[cfg(all(feature = "serde-hex", feature = "serde-struct"))]
+compile_error!("the `serde-hex` and `serde-struct` features are mutually exclusive");
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use fine::{FineAngle, FineRatio};
+use lab::{to_linear, Lab, Oklab};
+use math::round;
+use rgb::blend;
+
 mod angle;
+#[cfg(any(feature = "approx", test))]
+pub mod approx;
+mod builder;
+mod cvd;
+mod fine;
+mod gradient;
 mod hsl;
+mod hsv;
 mod integrations;
+mod lab;
+mod math;
+mod named;
+mod parse;
 mod ratio;
 mod rgb;
+mod ryb;
 
 pub use angle::*;
+pub use builder::*;
+pub use cvd::*;
+pub use gradient::*;
 pub use hsl::*;
+pub use hsv::*;
+pub use named::*;
+pub use parse::*;
 pub use ratio::*;
 pub use rgb::*;
 
+#[cfg(feature = "serde")]
+pub use integrations::serde::flexible as serde_flexible;
+#[cfg(feature = "serde")]
+pub use integrations::serde::hsl as serde_hsl;
+#[cfg(feature = "serde")]
+pub use integrations::serde::opaque_hex as serde_opaque_hex;
+
 /// A trait that can be used for converting between different color models
 /// and performing various transformations on them.
-pub trait Color {
+pub trait Color: Sized {
     type Alpha: Color;
 
     /// Converts `self` to its CSS string format.
@@ -26,7 +70,78 @@ pub trait Color {
     /// assert_eq!(salmon.to_css(), "rgb(250, 128, 114)");
     /// assert_eq!(opaque_salmon.to_css(), "rgba(250, 128, 114, 0.50)");
     /// ```
-    fn to_css(self) -> String;
+    fn to_css(self) -> String {
+        let mut css = String::new();
+        self.write_css(&mut css)
+            .expect("writing to a String can't fail");
+        css
+    }
+
+    /// Writes `self` in its CSS string format, like [`Color::to_css`], into `w`.
+    /// Reusing a single buffer this way avoids allocating a `String` for every color.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsla};
+    ///
+    /// let mut css = String::new();
+    ///
+    /// for color in [rgb(250, 128, 114), hsla(6, 93, 71, 0.5).to_rgb()] {
+    ///     css.clear();
+    ///     color.write_css(&mut css).unwrap();
+    ///
+    ///     assert_eq!(css, color.to_css());
+    /// }
+    /// ```
+    fn write_css(self, w: &mut impl fmt::Write) -> fmt::Result;
+
+    /// Converts `self` to its CSS string format like [`Color::to_css`], but drops the alpha
+    /// channel when it is fully opaque, writing `rgb()` or `hsl()` rather than `rgba()` or `hsla()`.
+    /// Fully transparent black is written as the `transparent` keyword.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgba, hsla};
+    ///
+    /// assert_eq!(rgba(250, 128, 114, 1.0).to_css_minimal(), "rgb(250, 128, 114)");
+    /// assert_eq!(rgba(250, 128, 114, 0.5).to_css_minimal(), "rgba(250, 128, 114, 0.50)");
+    /// assert_eq!(hsla(6, 93, 71, 1.0).to_css_minimal(), "hsl(6, 93%, 71%)");
+    /// assert_eq!(rgba(0, 0, 0, 0.0).to_css_minimal(), "transparent");
+    /// ```
+    fn to_css_minimal(self) -> String;
+
+    /// Converts `self` to its CSS Color Module Level 4 string format, which
+    /// separates channels with spaces and the alpha value with a slash.
+    /// The alpha value is expressed as a percentage.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, hsla};
+    ///
+    /// let salmon = rgb(250, 128, 114);
+    /// let opaque_salmon = rgba(250, 128, 114, 0.50);
+    /// let opaque_hsl_salmon = hsla(6, 93, 71, 0.50);
+    ///
+    /// assert_eq!(salmon.to_css_modern(), "rgb(250 128 114)");
+    /// assert_eq!(opaque_salmon.to_css_modern(), "rgb(250 128 114 / 50%)");
+    /// assert_eq!(opaque_hsl_salmon.to_css_modern(), "hsl(6 93% 71% / 50%)");
+    /// ```
+    fn to_css_modern(self) -> String;
+
+    /// Converts `self` into a CSS custom property declaration named `name`, in the
+    /// format `--name: <color>;` using [`Color::to_css`]. The leading `--` may be
+    /// left out of `name`. See [`to_css_vars`] to declare a whole theme at once.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsla};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).to_css_var("accent"), "--accent: rgb(250, 128, 114);");
+    /// assert_eq!(hsla(6, 93, 71, 0.5).to_css_var("--overlay"), "--overlay: hsla(6, 93%, 71%, 0.50);");
+    /// ```
+    fn to_css_var(self, name: &str) -> String {
+        format!("--{}: {};", name.trim_start_matches("--"), self.to_css())
+    }
 
     /// Converts `self` to a hex string in the format #rrggbb without
     /// alpha, and #rrggbbaa with alpha.
@@ -43,7 +158,124 @@ pub trait Color {
     /// assert_eq!(salmon.to_hex(), "#fa8072");
     /// assert_eq!(opaque_salmon.to_hex(), "#fa807280");
     /// ```
-    fn to_hex(self) -> String;
+    fn to_hex(self) -> String {
+        let mut hex = String::new();
+        self.write_hex(&mut hex)
+            .expect("writing to a String can't fail");
+        hex
+    }
+
+    /// Checks whether `self` is the color written as `hex`, in any of the `#rgb`, `#rgba`,
+    /// `#rrggbb` and `#rrggbbaa` formats, with or without the leading `#`. Strings that
+    /// aren't hex colors never match.
+    ///
+    /// Each channel of `self.to_rgb()` may be off by one from the parsed channel, to allow
+    /// for the rounding of converting from other color models such as HSL. The alpha
+    /// channel is only compared, with the same tolerance, when `hex` has one.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, hsl, rgba};
+    ///
+    /// let salmon = hsl(6, 93, 71);
+    ///
+    /// assert_eq!(salmon.to_hex(), "#fa7e70");
+    /// assert!(salmon.matches_hex("#fa7f71"));
+    /// assert!(salmon.matches_hex("FA7E70"));
+    /// assert!(!salmon.matches_hex("#fa8072"));
+    /// assert!(rgba(250, 126, 112, 0.5).matches_hex("#fa7e70"));
+    /// assert!(!rgba(250, 126, 112, 0.5).matches_hex("#fa7e70ff"));
+    /// ```
+    fn matches_hex(self, hex: &str) -> bool {
+        let expected = match parse::parse_hex(hex.strip_prefix('#').unwrap_or(hex)) {
+            Ok(expected) => expected,
+            Err(_) => return false,
+        };
+
+        let digits = hex.len() - usize::from(hex.starts_with('#'));
+        let actual = self.to_rgba();
+        let close = |lhs: Ratio, rhs: Ratio| lhs.as_u8().abs_diff(rhs.as_u8()) <= 1;
+
+        close(actual.r, expected.r)
+            && close(actual.g, expected.g)
+            && close(actual.b, expected.b)
+            && (!matches!(digits, 4 | 8) || close(actual.a, expected.a))
+    }
+
+    /// Writes `self` as a hex string, like [`Color::to_hex`], into `w`.
+    /// Reusing a single buffer this way avoids allocating a `String` for every color.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgba};
+    ///
+    /// let mut css = String::from("color: ");
+    /// rgba(250, 128, 114, 0.50).write_hex(&mut css).unwrap();
+    ///
+    /// assert_eq!(css, "color: #fa807280");
+    /// ```
+    fn write_hex(self, w: &mut impl fmt::Write) -> fmt::Result;
+
+    /// Converts `self` to a hex string in the format rrggbb without
+    /// alpha, and rrggbbaa with alpha, omitting the leading `#`.
+    ///
+    /// This will always be lowercase.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let salmon = rgb(250, 128, 114);
+    /// let opaque_salmon = rgba(250, 128, 114, 0.50);
+    ///
+    /// assert_eq!(salmon.to_hex_bare(), "fa8072");
+    /// assert_eq!(opaque_salmon.to_hex_bare(), "fa807280");
+    /// ```
+    fn to_hex_bare(self) -> String;
+
+    /// Converts `self` to the 3-digit `#rgb` shorthand without alpha, or the 4-digit `#rgba`
+    /// shorthand with alpha, as used by [`Color::to_hex`]. Returns `None` when the color can't be
+    /// shortened without losing precision, which is when any channel's two hex digits differ.
+    ///
+    /// This will always be lowercase.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 170, 0).to_hex_short(), Some(String::from("#fa0")));
+    /// assert_eq!(rgba(255, 170, 0, 0.8).to_hex_short(), Some(String::from("#fa0c")));
+    /// assert_eq!(rgb(255, 171, 0).to_hex_short(), None);
+    /// ```
+    fn to_hex_short(self) -> Option<String> {
+        let hex = self.to_hex_bare();
+        let digits = hex.as_bytes().chunks(2);
+
+        if digits.clone().all(|pair| pair[0] == pair[1]) {
+            Some(
+                core::iter::once('#')
+                    .chain(digits.map(|pair| pair[0] as char))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Packs `self` into an integer in the format `0x00RRGGBB` without
+    /// alpha, and `0xRRGGBBAA` with alpha.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let salmon = rgb(250, 128, 114);
+    /// let opaque_salmon = rgba(250, 128, 114, 0.50);
+    ///
+    /// assert_eq!(salmon.to_u32(), 0x00fa8072);
+    /// assert_eq!(opaque_salmon.to_u32(), 0xfa807280);
+    /// ```
+    fn to_u32(self) -> u32;
 
     /// Converts `self` into its RGB representation.
     /// When converting from a color model that supports an alpha channel
@@ -77,6 +309,13 @@ pub trait Color {
     /// When converting from a color model that supports an alpha channel
     /// (e.g. RGBA), the alpha value will not be preserved.
     ///
+    /// The hue is computed whenever the channels of `self` differ at all, so near-greys keep
+    /// their hue even when their saturation rounds down to `0%`. Only exact greys, whose hue
+    /// is undefined, get a hue of `0`.
+    ///
+    /// [`HSL`] stores its channels with more precision than `RGB`, so every `RGB` color
+    /// survives a round trip through `HSL` unchanged.
+    ///
     /// # Examples
     /// ```
     /// use farver::{Color, rgb, rgba, hsl};
@@ -84,8 +323,12 @@ pub trait Color {
     /// let tomato = rgb(255, 99, 71);
     /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(tomato.to_hsl(), hsl(9, 100, 64));
-    /// assert_eq!(opaque_tomato.to_hsl(), hsl(9, 100, 64));
+    /// assert_eq!(tomato.to_hsl().to_css(), "hsl(9, 100%, 64%)");
+    /// assert_eq!(opaque_tomato.to_hsl(), tomato.to_hsl());
+    /// assert_eq!(rgb(255, 0, 0).to_hsl(), hsl(0, 100, 50));
+    /// assert_eq!(rgb(128, 128, 129).to_hsl().hue(), 240);
+    /// assert_eq!(rgb(128, 128, 128).to_hsl().hue(), 0);
+    /// assert_eq!(rgb(250, 128, 114).to_hsl().to_rgb(), rgb(250, 128, 114));
     /// ```
     fn to_hsl(self) -> HSL;
 
@@ -95,18 +338,31 @@ pub trait Color {
     ///
     /// # Examples
     /// ```
-    /// use farver::{Color, rgb, rgba, hsl, hsla};
+    /// use farver::{Color, rgb, rgba};
     ///
     /// let tomato = rgb(255, 99, 71);
     /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(tomato.to_hsla(), hsla(9, 100, 64, 1.0));
-    /// assert_eq!(opaque_tomato.to_hsla(), hsla(9, 100, 64, 0.5));
+    /// assert_eq!(tomato.to_hsla().to_string(), "hsla(9, 100%, 64%, 1.00)");
+    /// assert_eq!(opaque_tomato.to_hsla().to_string(), "hsla(9, 100%, 64%, 0.50)");
+    /// assert_eq!(opaque_tomato.to_hsla(), tomato.to_hsla().fade(0.5));
     /// ```
     fn to_hsla(self) -> HSLA;
 
+    /// Converts `self` into its [`HSV`] representation, dropping any alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl, hsv};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).to_hsv(), hsv(0, 100, 100));
+    /// assert_eq!(hsl(6, 93, 71).to_hsv().to_string(), "hsv(6, 55%, 98%)");
+    /// ```
+    fn to_hsv(self) -> HSV;
+
     /// Increases the saturation of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The resulting saturation is clamped to the 0-100% range.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-saturate).
     ///
     /// # Examples
@@ -117,12 +373,13 @@ pub trait Color {
     /// let cornflower_blue = rgb(100, 149, 237);
     ///
     /// assert_eq!(salmon.saturate(percent(7)), hsla(6, 100, 71, 1.0));
-    /// assert_eq!(cornflower_blue.saturate(percent(10)), rgb(92, 146, 246));
+    /// assert_eq!(cornflower_blue.saturate(percent(10)), rgb(91, 146, 246));
     /// ```
     fn saturate(self, amount: Ratio) -> Self;
 
     /// Decreases the saturation of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The resulting saturation is clamped to the 0-100% range.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-desaturate).
     ///
     /// # Examples
@@ -132,13 +389,55 @@ pub trait Color {
     /// let tomato = rgba(255, 99, 71, 1.0);
     /// let cornflower_blue = rgb(100, 149, 237);
     ///
-    /// assert_eq!(tomato.desaturate(percent(10)), rgba(246, 105, 80, 1.0));
-    /// assert_eq!(cornflower_blue.desaturate(percent(33)), rgb(129, 157, 209));
+    /// assert_eq!(tomato.desaturate(percent(10)), rgba(246, 106, 80, 1.0));
+    /// assert_eq!(cornflower_blue.desaturate(percent(33)), rgb(128, 157, 209));
     /// ```
     fn desaturate(self, amount: Ratio) -> Self;
 
+    /// Increases the saturation of `self` by a fraction of its current saturation,
+    /// so that `s = s * (1 + amount)`.
+    /// Greys have no saturation to scale, so unlike with [`Color::saturate`], they stay grey
+    /// rather than picking up the color of their (meaningless) hue, and near greys only
+    /// gain a hint of color. The saturation stops at 100%, so fully saturated colors are
+    /// left unchanged.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla, percent, rgb};
+    ///
+    /// let grey = rgb(128, 128, 128);
+    ///
+    /// assert_eq!(hsl(200, 40, 50).saturate_relative(percent(50)).to_string(), "hsl(200, 60%, 50%)");
+    /// assert_eq!(hsla(200, 80, 50, 0.5).saturate_relative(percent(50)), hsla(200, 100, 50, 0.5));
+    /// assert_eq!(grey.saturate_relative(percent(100)), grey);
+    /// assert_ne!(grey.saturate(percent(10)), grey);
+    /// ```
+    fn saturate_relative(self, amount: Ratio) -> Self;
+
+    /// Decreases the saturation of `self` by a fraction of its current saturation,
+    /// so that `s = s * (1 - amount)`.
+    /// Unlike with [`Color::desaturate`], any `amount` below 100% keeps some of the color
+    /// of `self`, so that repeatedly desaturating a color fades it out gradually, without
+    /// ever reaching grey. An `amount` of 100% gives the same grey as [`Color::greyscale`].
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla, percent};
+    ///
+    /// let sky = hsla(200, 80, 60, 0.5);
+    ///
+    /// assert_eq!(sky.desaturate_relative(percent(25)).to_string(), "hsla(200, 60%, 60%, 0.50)");
+    /// assert_eq!(sky.desaturate(percent(80)), hsla(200, 0, 60, 0.5));
+    /// assert_eq!(sky.desaturate_relative(percent(80)).to_string(), "hsla(200, 16%, 60%, 0.50)");
+    /// assert_eq!(sky.desaturate_relative(percent(100)), sky.greyscale());
+    /// ```
+    fn desaturate_relative(self, amount: Ratio) -> Self;
+
     /// Increases the lightness of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The resulting lightness is clamped to the 0-100% range.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-lighten).
     ///
     /// # Examples
@@ -149,12 +448,13 @@ pub trait Color {
     /// let cornflower_blue = rgb(100, 149, 237);
     ///
     /// assert_eq!(tomato.lighten(percent(20)), rgba(255, 185, 173, 1.0));
-    /// assert_eq!(cornflower_blue.lighten(percent(33)), rgb(251, 253, 255));
+    /// assert_eq!(cornflower_blue.lighten(percent(33)), rgb(251, 252, 254));
     /// ```
     fn lighten(self, amount: Ratio) -> Self;
 
     /// Decreases the lightness of `self` by an absolute amount.
     /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The resulting lightness is clamped to the 0-100% range.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-darken).
     ///
     /// # Examples
@@ -165,10 +465,141 @@ pub trait Color {
     /// let cornflower_blue = rgb(100, 149, 237);
     ///
     /// assert_eq!(tomato.darken(percent(20)), rgba(224, 34, 0, 1.0));
-    /// assert_eq!(cornflower_blue.darken(percent(33)), rgb(18, 65, 152));
+    /// assert_eq!(cornflower_blue.darken(percent(33)), rgb(18, 65, 151));
     /// ```
     fn darken(self, amount: Ratio) -> Self;
 
+    /// Increases the lightness of `self` by a fraction of its current lightness,
+    /// so that `l = l * (1 + amount)`.
+    /// Unlike [`Color::lighten`], darker colors change less than lighter ones, which makes for
+    /// subtler, proportional adjustments.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The resulting lightness is clamped to the 0-100% range.
+    /// For more, see Sass' [scale-color](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla, percent};
+    ///
+    /// let dark_slate = hsl(210, 30, 20);
+    /// let sky = hsla(200, 80, 60, 0.5);
+    ///
+    /// assert_eq!(dark_slate.lighten_relative(percent(50)).to_string(), "hsl(210, 30%, 30%)");
+    /// assert_eq!(sky.lighten_relative(percent(50)).to_string(), "hsla(200, 80%, 90%, 0.50)");
+    /// ```
+    fn lighten_relative(self, amount: Ratio) -> Self;
+
+    /// Decreases the lightness of `self` by a fraction of its current lightness,
+    /// so that `l = l * (1 - amount)`.
+    /// Unlike [`Color::darken`], darker colors change less than lighter ones, which makes for
+    /// subtler, proportional adjustments.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// The resulting lightness is clamped to the 0-100% range.
+    /// For more, see Sass' [scale-color](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla, percent};
+    ///
+    /// let slate = hsl(210, 30, 40);
+    /// let sky = hsla(200, 80, 60, 0.5);
+    ///
+    /// assert_eq!(slate.darken_relative(percent(40)).to_string(), "hsl(210, 30%, 24%)");
+    /// assert_eq!(sky.darken_relative(percent(25)).to_string(), "hsla(200, 80%, 45%, 0.50)");
+    /// ```
+    fn darken_relative(self, amount: Ratio) -> Self;
+
+    /// Increases the perceived lightness of `self` by an absolute amount, adjusting the L*
+    /// channel of its [CIE L*a*b*](https://en.wikipedia.org/wiki/CIELAB_color_space)
+    /// representation, where 100% is white. Unlike [`Color::lighten`], equal amounts give
+    /// equal steps in perceived lightness, whatever the hue, which makes for evenly spaced
+    /// tint ramps. Colors pushed outside of the sRGB gamut lose chroma until they fit, keeping
+    /// the requested lightness. Preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, percent};
+    ///
+    /// assert_eq!(rgb(0, 0, 255).lighten_lab(percent(20)), rgb(138, 93, 255));
+    /// assert_eq!(rgba(128, 128, 128, 0.5).lighten_lab(percent(100)), rgba(255, 255, 255, 0.5));
+    /// ```
+    fn lighten_lab(self, amount: Ratio) -> Self;
+
+    /// Decreases the perceived lightness of `self` by an absolute amount, adjusting the L*
+    /// channel of its CIE L*a*b* representation. This is the counterpart of
+    /// [`Color::lighten_lab`], and works the same way.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, percent};
+    ///
+    /// assert_eq!(rgb(255, 255, 0).darken_lab(percent(20)), rgb(197, 197, 0));
+    /// assert_eq!(rgba(128, 128, 128, 0.5).darken_lab(percent(100)), rgba(0, 0, 0, 0.5));
+    /// ```
+    fn darken_lab(self, amount: Ratio) -> Self;
+
+    /// Increases the brightness of `self` like raising a photograph's exposure, multiplying
+    /// the amount of light in each channel by `1 + amount`, so that `100%` doubles it. The
+    /// channels are scaled in linear light and then encoded back into sRGB, which keeps the
+    /// color's hue and looks more natural than [`Color::lighten`]. Channels pushed past full
+    /// brightness are clamped. Preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, percent};
+    ///
+    /// assert_eq!(rgb(128, 128, 128).brighten(percent(100)), rgb(176, 176, 176));
+    /// assert_eq!(rgba(100, 50, 0, 0.5).brighten(percent(50)), rgba(121, 62, 0, 0.5));
+    /// ```
+    fn brighten(self, amount: Ratio) -> Self;
+
+    /// Decreases the brightness of `self` like lowering a photograph's exposure, multiplying
+    /// the amount of light in each channel by `1 - amount`, so that `50%` halves it and `100%`
+    /// gives black. This is the counterpart of [`Color::brighten`], and works the same way.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, percent};
+    ///
+    /// assert_eq!(rgb(128, 128, 128).dim(percent(50)), rgb(92, 92, 92));
+    /// assert_eq!(rgb(128, 128, 128).dim(percent(100)), rgb(0, 0, 0));
+    /// ```
+    fn dim(self, amount: Ratio) -> Self;
+
+    /// Scales the saturation of `self` towards a bound by a fraction of the distance to it.
+    /// A positive `amount` moves the saturation towards 100%, while a negative one moves it
+    /// towards 0%, so that `1.0` and `-1.0` fully saturate and desaturate the color respectively.
+    /// Unlike [`Color::saturate`], the result never overshoots, whatever the starting saturation.
+    /// The `amount` is clamped to the `-1.0-1.0` range.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Sass' [scale-color](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla};
+    ///
+    /// assert_eq!(hsl(6, 60, 71).scale_saturation(0.5), hsl(6, 80, 71));
+    /// assert_eq!(hsla(6, 60, 71, 0.5).scale_saturation(-0.5), hsla(6, 30, 71, 0.5));
+    /// ```
+    fn scale_saturation(self, amount: f32) -> Self;
+
+    /// Scales the lightness of `self` towards a bound by a fraction of the distance to it.
+    /// A positive `amount` moves the lightness towards 100%, while a negative one moves it
+    /// towards 0%, so that `1.0` and `-1.0` give white and black respectively.
+    /// Unlike [`Color::lighten`], the result never overshoots, whatever the starting lightness.
+    /// The `amount` is clamped to the `-1.0-1.0` range.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Sass' [scale-color](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla};
+    ///
+    /// assert_eq!(hsl(6, 93, 60).scale_lightness(0.5), hsl(6, 93, 80));
+    /// assert_eq!(hsla(6, 93, 60, 0.5).scale_lightness(-0.5), hsla(6, 93, 30, 0.5));
+    /// ```
+    fn scale_lightness(self, amount: f32) -> Self;
+
     /// Decreases the transparency (or increase the opacity) of `self`, making it more opaque.
     /// For opaque colors, converts into the alpha equivalent of `self`, and then increases the opacity.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fadein).
@@ -203,6 +634,7 @@ pub trait Color {
 
     /// Sets the absolute opacity of `self`, and returns the alpha equivalent.
     /// Can be applied to colors whether they already have an opacity value or not.
+    /// The opacity can also be given as a fraction, so `0.25` is the same as `percent(25)`.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fade).
     ///
     /// # Examples
@@ -214,8 +646,42 @@ pub trait Color {
     ///
     /// assert_eq!(tomato.fade(percent(25)), rgba(255, 99, 71, 0.25));
     /// assert_eq!(cornflower_blue.fade(percent(50)), rgba(100, 149, 237, 0.5));
+    /// assert_eq!(cornflower_blue.fade(0.5), rgba(100, 149, 237, 0.5));
+    /// ```
+    fn fade(self, amount: impl Into<Ratio>) -> Self::Alpha;
+
+    /// Computes the color seen when `self` is composited over itself `times` times, as happens
+    /// when stacking layers of the same semi-transparent color (e.g. shadows). The channels are
+    /// kept, while the opacity grows to `1 - (1 - alpha)^times`.
+    /// Stacking `0` layers is fully transparent, and a single layer is `self` itself.
+    ///
+    /// # Examples
     /// ```
-    fn fade(self, amount: Ratio) -> Self::Alpha;
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let shadow = rgba(0, 0, 0, 0.5);
+    ///
+    /// assert_eq!(shadow.stack_alpha(2).to_css(), "rgba(0, 0, 0, 0.75)");
+    /// assert_eq!(shadow.stack_alpha(0), rgba(0, 0, 0, 0.0));
+    /// assert_eq!(rgb(250, 128, 114).stack_alpha(3), rgba(250, 128, 114, 1.0));
+    /// ```
+    fn stack_alpha(self, times: u32) -> Self::Alpha;
+
+    /// Sets the absolute opacity of `self` from a float within `0.0-1.0`, and returns the alpha
+    /// equivalent, like [`Color::fade`]. Values outside of that range are clamped into it,
+    /// and `NaN` gives a fully transparent color.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, hsl, hsla};
+    ///
+    /// assert_eq!(rgb(1, 2, 3).with_alpha_f32(0.5), rgba(1, 2, 3, 0.5));
+    /// assert_eq!(hsl(6, 93, 71).with_alpha_f32(1.5), hsla(6, 93, 71, 1.0));
+    /// assert_eq!(rgb(1, 2, 3).with_alpha_f32(-0.5), rgba(1, 2, 3, 0.0));
+    /// ```
+    fn with_alpha_f32(self, a: f32) -> Self::Alpha {
+        self.fade(Ratio::from(a))
+    }
 
     /// Rotate the hue angle of `self` in either direction.
     /// Returns the appropriate `RGB` representation of the color once it has been spun.
@@ -233,8 +699,32 @@ pub trait Color {
     /// ```
     fn spin(self, amount: Angle) -> Self;
 
+    /// Derives an accent from `self`: a related color that still stands apart from it, such as
+    /// for highlighting a control in a theme built around `self`.
+    /// The accent is `self` spun by 30° with [`Color::spin`], and then lightened by 20% with
+    /// [`Color::lighten`] when `self` has an HSL lightness below 50%, or darkened by 20% with
+    /// [`Color::darken`] otherwise. Preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla};
+    ///
+    /// assert_eq!(hsl(210, 60, 30).accent(), hsl(240, 60, 50));
+    /// assert_eq!(hsla(6, 93, 71, 0.5).accent(), hsla(36, 93, 51, 0.5));
+    /// ```
+    fn accent(self) -> Self;
+
     /// Mixes two colors (`self` and any other `Color`) together in variable proportion.
     /// Takes opacity into account in the calculations.
+    /// The colors are always blended in the RGB color space, but the result is returned in the
+    /// alpha variant of `self`'s color model. Use [`Color::mix_rgb`] or [`Color::mix_hsl`] when
+    /// the result's model shouldn't depend on the receiver.
+    /// The `weight` is the share of `self` in the result and always lies within 0-100%, as a
+    /// [`Ratio`] can't hold anything larger; [`percent`] clamps larger values to 100%.
+    /// It can also be given as a fraction, so `0.25` is the same as `percent(25)`.
+    /// A weight is stored in 256 steps, with percentages and fractions rounded to the nearest
+    /// one, and only that step matters: `percent(50)`, `0.5` and `Ratio::from_u8(128)` are the
+    /// same weight and give the same mix.
     /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-mix).
     ///
     /// # Examples
@@ -247,155 +737,808 @@ pub trait Color {
     ///
     /// assert_eq!(red.mix(navy, percent(50)).to_string(), "hsla(347, 65%, 29%, 1.00)");
     /// assert_eq!(golden.mix(navy, percent(25)), rgba(61, 42, 63, 1.0));
+    /// assert_eq!(golden.mix(navy, 0.25), rgba(61, 42, 63, 1.0));
     /// ```
-    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha;
+    fn mix<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self::Alpha;
 
-    /// Mixes `self` with white in variable proportion.
-    /// Equivalent to calling `mix()` with `white` (`rgb(255, 255, 255)`).
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-tint).
+    /// Mixes `self` and `other` together like [`Color::mix`], but treats both colors as fully
+    /// opaque and returns `self`'s own color model, so mixing two `RGB` colors gives an `RGB`.
+    /// Any alpha channels are ignored, and an `RGBA` or `HSLA` result is fully opaque.
     ///
     /// # Examples
     /// ```
     /// use farver::{Color, rgb, rgba, hsl, percent};
     ///
-    /// let red = hsl(10, 90, 50);
     /// let golden = rgb(243, 166, 13);
+    /// let navy = rgb(0, 0, 80);
     ///
-    /// assert_eq!(red.tint(percent(10)), hsl(10, 92, 95));
-    /// assert_eq!(golden.tint(percent(25)), rgb(252, 233, 194));
+    /// assert_eq!(golden.mix_opaque(navy, percent(25)), rgb(61, 42, 63));
+    /// assert_eq!(golden.mix_opaque(navy, percent(25)), golden.mix(navy, percent(25)).to_rgb());
+    /// assert_eq!(rgba(243, 166, 13, 0.5).mix_opaque(navy, percent(25)), rgba(61, 42, 63, 1.0));
+    /// assert_eq!(hsl(10, 90, 50).mix_opaque(navy, percent(50)).to_string(), "hsl(347, 65%, 29%)");
     /// ```
-    fn tint(self, weight: Ratio) -> Self;
+    fn mix_opaque<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self;
 
-    /// Mixes `self` with white in variable proportion.
-    /// Equivalent to calling `mix()` with `black` (`rgb(0, 0, 0)`).
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-shade).
+    /// Linearly interpolates from `self` to `other` in the RGB color space, returning the
+    /// alpha variant of `self`'s color model. A `t` of 0% gives `self` and 100% gives `other`,
+    /// with every channel, alpha included, moving the same proportion of the way.
+    ///
+    /// This differs from [`Color::mix`] in two ways: `mix` takes the share of `self` rather
+    /// than the distance travelled towards `other`, and, like Less, it shifts the color
+    /// channels towards the more opaque of the two colors. The two agree when both colors
+    /// have the same alpha, so that `a.lerp(b, percent(25))` equals `a.mix(b, percent(75))`.
     ///
     /// # Examples
     /// ```
-    /// use farver::{Color, rgb, rgba, hsl, percent};
+    /// use farver::{Color, rgb, rgba, percent};
     ///
-    /// let red = hsl(10, 90, 50);
-    /// let golden = rgb(243, 166, 13);
+    /// let black = rgb(0, 0, 0);
+    /// let salmon = rgba(250, 128, 114, 0.0);
     ///
-    /// assert_eq!(red.shade(percent(10)), hsl(10, 92, 5));
-    /// assert_eq!(golden.shade(percent(25)), rgb(61, 42, 3));
+    /// assert_eq!(black.lerp(salmon, percent(0)), rgba(0, 0, 0, 1.0));
+    /// assert_eq!(black.lerp(salmon, percent(100)), salmon);
+    /// assert_eq!(black.lerp(salmon, 0.5).to_string(), "rgba(125, 64, 57, 0.50)");
     /// ```
-    fn shade(self, weight: Ratio) -> Self;
+    fn lerp<T: Color>(self, other: T, t: impl Into<Ratio>) -> Self::Alpha;
 
-    /// Remove all saturation from `self` in the HSL color space.
-    /// Equivalent to calling `desaturate(0)` on a color.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-greyscale).
+    /// Mixes `self` and `other` together in the RGB color space, always returning an `RGBA`.
+    /// Behaves exactly like [`Color::mix`] called on `self.to_rgba()`, regardless of the color
+    /// model of `self`.
     ///
     /// # Examples
     /// ```
-    /// use farver::{Color, rgb, rgba};
+    /// use farver::{Color, rgba, hsl, percent};
     ///
-    /// let tomato = rgba(255, 99, 71, 1.0);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let red = hsl(10, 90, 50);
+    /// let navy = rgba(0, 0, 80, 1.0);
     ///
-    /// assert_eq!(tomato.greyscale(), rgba(163, 163, 163, 1.0));
-    /// assert_eq!(cornflower_blue.greyscale(), rgb(169, 169, 169));
+    /// assert_eq!(red.mix_rgb(navy, percent(50)), rgba(121, 26, 46, 1.0));
+    /// assert_eq!(red.mix_rgb(navy, percent(50)), red.to_rgba().mix(navy, percent(50)));
     /// ```
-    fn greyscale(self) -> Self;
-
-    #[cfg(feature = "ansi_term")]
-    fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
-        &self,
-        input: I,
-    ) -> ansi_term::ANSIGenericString<'a, S>
-    where
-        I: Into<Cow<'a, S>>,
-        <S as ToOwned>::Owned: Debug;
-}
+    fn mix_rgb<T: Color>(self, other: T, weight: impl Into<Ratio>) -> RGBA {
+        self.to_rgba().mix(other, weight)
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::{deg, hsl, hsla, percent, rgb, rgba, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+    /// Mixes `self` and `other` together in the HSL color space, always returning an `HSLA`.
+    /// Hue, saturation, lightness and alpha are each interpolated linearly, with `weight` being
+    /// the proportion of `self` in the result. The hue travels the shorter way around the color wheel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsla, hsl, percent};
+    ///
+    /// let red = hsl(350, 80, 50);
+    /// let orange = rgb(255, 170, 0);
+    ///
+    /// assert_eq!(red.mix_hsl(orange, percent(50)).to_string(), "hsla(15, 90%, 50%, 1.00)");
+    /// assert_eq!(red.mix_hsl(orange, percent(100)), hsla(350, 80, 50, 1.0));
+    /// ```
+    fn mix_hsl<T: Color>(self, other: T, weight: impl Into<Ratio>) -> HSLA {
+        let lhs = self.to_hsla();
+        let rhs = other.to_hsla();
+        let w = weight.into().as_f32();
 
-    pub trait ApproximatelyEq {
-        fn approximately_eq(self, other: Self) -> bool;
-    }
+        let lerp = |lhs: f32, rhs: f32| lhs * w + rhs * (1.0 - w);
 
-    impl ApproximatelyEq for u8 {
-        fn approximately_eq(self, other: Self) -> bool {
-            self == other || self + 1 == other || self - 1 == other
-        }
-    }
+        // Find the signed distance from the right hue to the left one, taking the shorter path.
+        let mut delta = lhs.h.as_f32() - rhs.h.as_f32();
 
-    impl ApproximatelyEq for u16 {
-        fn approximately_eq(self, other: Self) -> bool {
-            self == other || self + 1 == other || self - 1 == other
+        if delta > 180.0 {
+            delta -= 360.0;
+        } else if delta < -180.0 {
+            delta += 360.0;
         }
-    }
 
-    impl ApproximatelyEq for Angle {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.degrees().approximately_eq(other.degrees())
+        HSLA {
+            h: FineAngle::from_f32(rhs.h.as_f32() + delta * w),
+            s: FineRatio::from_f32(lerp(lhs.s.as_f32(), rhs.s.as_f32())),
+            l: FineRatio::from_f32(lerp(lhs.l.as_f32(), rhs.l.as_f32())),
+            a: Ratio::from_f32(lerp(lhs.a.as_f32(), rhs.a.as_f32()).clamp(0.0, 1.0)),
         }
     }
 
-    impl ApproximatelyEq for Ratio {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.as_u8().approximately_eq(other.as_u8())
+    /// Mixes `self` and `other` together in the [Oklab](https://bottosson.github.io/posts/oklab/)
+    /// color space, always returning an `RGBA`. Oklab is designed to be perceptually uniform,
+    /// so the mix avoids the muddy, dark midpoints that [`Color::mix`] gives for colors far
+    /// apart on the color wheel. As with `mix`, `weight` is the proportion of `self` in the
+    /// result. The alpha channels are interpolated linearly.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, percent};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let lime = rgb(0, 255, 0);
+    ///
+    /// assert_eq!(red.mix(lime, percent(50)), rgba(128, 127, 0, 1.0));
+    /// assert_eq!(red.mix_perceptual(lime, percent(50)), rgba(208, 168, 0, 1.0));
+    /// assert_eq!(red.mix_perceptual(lime, percent(100)), rgba(255, 0, 0, 1.0));
+    /// ```
+    fn mix_perceptual<T: Color>(self, other: T, weight: impl Into<Ratio>) -> RGBA {
+        let lhs = self.to_rgba();
+        let rhs = other.to_rgba();
+        let w = weight.into().as_f32();
+
+        let lhs_lab = Oklab::from_rgb(lhs.to_rgb());
+        let rhs_lab = Oklab::from_rgb(rhs.to_rgb());
+        let lerp = |lhs: f32, rhs: f32| lhs * w + rhs * (1.0 - w);
+
+        let RGB { r, g, b } = Oklab {
+            l: lerp(lhs_lab.l, rhs_lab.l),
+            a: lerp(lhs_lab.a, rhs_lab.a),
+            b: lerp(lhs_lab.b, rhs_lab.b),
         }
-    }
+        .to_rgb();
 
-    impl ApproximatelyEq for RGB {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.r.approximately_eq(other.r)
-                    && self.g.approximately_eq(other.g)
-                    && self.b.approximately_eq(other.b)
+        RGBA {
+            r,
+            g,
+            b,
+            a: Ratio::from_f32(lerp(lhs.a.as_f32(), rhs.a.as_f32()).clamp(0.0, 1.0)),
         }
     }
 
-    impl ApproximatelyEq for RGBA {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.r.approximately_eq(other.r)
-                    && self.g.approximately_eq(other.g)
-                    && self.b.approximately_eq(other.b)
-                    && self.a == other.a
+    /// Mixes `self` and `other` together like paints rather than lights, always returning an
+    /// `RGBA`. Mixing light adds colors up, so blue and yellow make grey, while mixing paint
+    /// subtracts them, so blue and yellow make green.
+    ///
+    /// The colors are mixed within the red, yellow and blue primaries of the painter's color
+    /// wheel, converting from and to RGB with the approximation by Sugita and Takahashi of
+    /// the model in "Paint Inspired Color Mixing and Compositing for Visualization" by Gossett
+    /// and Chen. As with [`Color::mix`], `weight` is the proportion of `self` in the result.
+    /// The alpha channels are interpolated linearly.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, percent};
+    ///
+    /// let blue = rgb(0, 0, 255);
+    /// let yellow = rgb(255, 255, 0);
+    ///
+    /// assert_eq!(blue.mix(yellow, percent(50)), rgba(127, 127, 128, 1.0));
+    /// assert_eq!(blue.mix_subtractive(yellow, percent(50)), rgba(0, 128, 1, 1.0));
+    /// assert_eq!(blue.mix_subtractive(yellow, percent(100)), rgba(0, 0, 255, 1.0));
+    /// ```
+    fn mix_subtractive<T: Color>(self, other: T, weight: impl Into<Ratio>) -> RGBA {
+        let lhs = self.to_rgba();
+        let rhs = other.to_rgba();
+        let w = weight.into().as_f32();
+
+        let to_ryb =
+            |color: RGBA| ryb::from_rgb([color.r.as_f32(), color.g.as_f32(), color.b.as_f32()]);
+        let lerp = |lhs: f32, rhs: f32| lhs * w + rhs * (1.0 - w);
+
+        let [lhs_r, lhs_y, lhs_b] = to_ryb(lhs);
+        let [rhs_r, rhs_y, rhs_b] = to_ryb(rhs);
+        let [r, g, b] = ryb::to_rgb([lerp(lhs_r, rhs_r), lerp(lhs_y, rhs_y), lerp(lhs_b, rhs_b)]);
+        let channel = |value: f32| Ratio::from_f32(value.clamp(0.0, 1.0));
+
+        RGBA {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+            a: channel(lerp(lhs.a.as_f32(), rhs.a.as_f32())),
         }
     }
 
-    impl ApproximatelyEq for HSL {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.h.approximately_eq(other.h)
-                    && self
-                        .s
-                        .as_percentage()
-                        .approximately_eq(other.s.as_percentage())
-                    && self
-                        .l
-                        .as_percentage()
-                        .approximately_eq(other.l.as_percentage())
+    /// Composites `self` over `background` using the Porter-Duff "source over" operator,
+    /// returning the color that would be seen when `self` is painted on top of `background`.
+    /// Unlike [`Color::mix`], the result depends only on the colors' alpha channels.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let translucent_red = rgba(255, 0, 0, 0.5);
+    ///
+    /// assert_eq!(translucent_red.over(rgb(255, 255, 255)), rgba(255, 127, 127, 1.0));
+    /// assert_eq!(
+    ///     translucent_red.over(rgba(0, 0, 255, 0.5)).to_string(),
+    ///     "rgba(170, 0, 85, 0.75)"
+    /// );
+    /// ```
+    fn over<T: Color>(self, background: T) -> RGBA {
+        let RGBA {
+            r: r_src,
+            g: g_src,
+            b: b_src,
+            a: a_src,
+        } = self.to_rgba();
+
+        let RGBA {
+            r: r_dst,
+            g: g_dst,
+            b: b_dst,
+            a: a_dst,
+        } = background.to_rgba();
+
+        let a_src = a_src.as_f32();
+        let a_dst = a_dst.as_f32() * (1.0 - a_src);
+        let a = (a_src + a_dst).min(1.0);
+
+        // Nothing shows through two fully transparent colors.
+        if a == 0.0 {
+            return rgba(0, 0, 0, 0.0);
+        }
+
+        let blend = |src: Ratio, dst: Ratio| {
+            Ratio::from_f32(((src.as_f32() * a_src + dst.as_f32() * a_dst) / a).min(1.0))
+        };
+
+        RGBA {
+            r: blend(r_src, r_dst),
+            g: blend(g_src, g_dst),
+            b: blend(b_src, b_dst),
+            a: Ratio::from_f32(a),
+        }
+    }
+
+    /// Composites `self` over an opaque `background` and drops the alpha channel, returning
+    /// the opaque color that would be seen. Useful right before [`Color::to_hex`] when the output
+    /// doesn't support transparency; pass `rgb(255, 255, 255)` to flatten onto white.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(rgba(0, 0, 0, 0.5).flatten(white), rgb(127, 127, 127));
+    /// assert_eq!(rgba(250, 128, 114, 0.0).flatten(white), white);
+    /// ```
+    fn flatten(self, background: RGB) -> RGB {
+        self.over(background).to_rgb()
+    }
+
+    /// Mixes `self` with white in variable proportion.
+    /// Equivalent to calling `mix()` with `white` (`rgb(255, 255, 255)`), so the `weight` is
+    /// limited to 0-100% in the same way.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-tint).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, hsl, percent};
+    ///
+    /// let red = hsl(10, 90, 50);
+    /// let golden = rgb(243, 166, 13);
+    ///
+    /// assert_eq!(red.tint(percent(10)).to_string(), "hsl(10, 92%, 95%)");
+    /// assert_eq!(golden.tint(percent(25)), rgb(252, 233, 194));
+    /// ```
+    fn tint(self, weight: impl Into<Ratio>) -> Self;
+
+    /// Mixes `self` with white in variable proportion.
+    /// Equivalent to calling `mix()` with `black` (`rgb(0, 0, 0)`), so the `weight` is
+    /// limited to 0-100% in the same way.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-shade).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba, hsl, percent};
+    ///
+    /// let red = hsl(10, 90, 50);
+    /// let golden = rgb(243, 166, 13);
+    ///
+    /// assert_eq!(red.shade(percent(10)).to_string(), "hsl(10, 92%, 5%)");
+    /// assert_eq!(golden.shade(percent(25)), rgb(61, 42, 3));
+    /// ```
+    fn shade(self, weight: impl Into<Ratio>) -> Self;
+
+    /// Generates a [Tailwind](https://tailwindcss.com/docs/customizing-colors)-style scale of
+    /// 11 shades from `self`, for the steps 50, 100, 200 through 900, and 950, in that order.
+    /// Step 500 is `self` itself, the lighter steps are [tinted](Color::tint) with white, and
+    /// the darker steps are [shaded](Color::shade) with black. The share of white or black
+    /// mixed in at each step is:
+    ///
+    /// | Step  | 50  | 100 | 200 | 300 | 400 | 500 | 600 | 700 | 800 | 900 | 950 |
+    /// |-------|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|-----|
+    /// | White | 90% | 80% | 60% | 40% | 20% |     |     |     |     |     |     |
+    /// | Black |     |     |     |     |     |     | 20% | 40% | 60% | 80% | 90% |
+    ///
+    /// Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let shades = rgb(59, 130, 246).shades();
+    ///
+    /// assert_eq!(shades[0], rgb(235, 242, 254));
+    /// assert_eq!(shades[5], rgb(59, 130, 246));
+    /// assert_eq!(shades[10], rgb(6, 13, 25));
+    /// ```
+    fn shades(self) -> [RGB; 11] {
+        const WEIGHTS: [u8; 5] = [10, 20, 40, 60, 80];
+
+        let base = self.to_rgb();
+        let mut shades = [base; 11];
+
+        for (i, weight) in WEIGHTS.into_iter().enumerate() {
+            shades[i] = base.tint(percent(weight));
+            shades[10 - i] = base.shade(percent(weight));
+        }
+
+        shades
+    }
+
+    /// Remove all saturation from `self` in the HSL color space.
+    /// Equivalent to calling `desaturate(0)` on a color.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-greyscale).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// let tomato = rgba(255, 99, 71, 1.0);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.greyscale(), rgba(163, 163, 163, 1.0));
+    /// assert_eq!(cornflower_blue.greyscale(), rgb(169, 169, 169));
+    /// ```
+    fn greyscale(self) -> Self;
+
+    /// Inverts the HSL lightness of `self`, so that `l` becomes `100% - l`, keeping its hue,
+    /// saturation and any alpha channel. Light colors turn into dark colors of the same hue
+    /// and the other way round, which makes for gentler dark mode variants than inverting
+    /// each RGB channel, as that also turns the hue around.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, hsl, hsla, rgb};
+    ///
+    /// assert_eq!(hsl(210, 80, 85).invert_lightness(), hsl(210, 80, 15));
+    /// assert_eq!(hsla(6, 93, 71, 0.5).invert_lightness(), hsla(6, 93, 29, 0.5));
+    /// assert_eq!(rgb(255, 255, 255).invert_lightness(), rgb(0, 0, 0));
+    /// ```
+    fn invert_lightness(self) -> Self;
+
+    /// Checks whether `self` is a neutral grey, ignoring any alpha channel.
+    /// An `RGB` color is grey when its red, green and blue channels are exactly equal.
+    /// An `HSL` color is grey when its saturation is zero, or when it converts to an `RGB`
+    /// color with equal channels. This tolerates the tiny saturations left behind by
+    /// converting from `RGB`, which are lost again when converting back.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl};
+    ///
+    /// assert!(rgb(128, 128, 128).is_grayscale());
+    /// assert!(!rgb(128, 128, 129).is_grayscale());
+    /// assert!(hsl(200, 0, 40).is_grayscale());
+    /// assert!(hsl(200, 100, 100).is_grayscale());
+    /// ```
+    // Takes `self` by value like every other method, as all colors are `Copy`.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_grayscale(self) -> bool;
+
+    /// Finds which of the red, green and blue channels of `self` is the largest, as a quick
+    /// way to bucket colors or tell whether one is reddish. Any alpha channel is ignored.
+    ///
+    /// When two channels tie for the largest, the first of red, green and blue wins, so
+    /// yellow is [`Channel::Red`]. Greys, where all three channels are equal, have no dominant
+    /// channel and return `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Channel, Color, rgb, hsl};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).dominant_channel(), Some(Channel::Red));
+    /// assert_eq!(hsl(200, 40, 70).dominant_channel(), Some(Channel::Blue));
+    /// assert_eq!(rgb(255, 255, 0).dominant_channel(), Some(Channel::Red));
+    /// assert_eq!(rgb(128, 128, 128).dominant_channel(), None);
+    /// ```
+    fn dominant_channel(self) -> Option<Channel> {
+        let RGB { r, g, b } = self.to_rgb();
+
+        if r == g && g == b {
+            None
+        } else if r >= g && r >= b {
+            Some(Channel::Red)
+        } else if g >= b {
+            Some(Channel::Green)
+        } else {
+            Some(Channel::Blue)
+        }
+    }
+
+    /// Applies `f` to each of the red, green and blue channels of `self`, for custom
+    /// per-channel transforms such as gamma curves. Colors in the HSL color model are converted
+    /// into RGB, transformed, and converted back, so their hue, saturation and lightness may
+    /// shift slightly even when `f` leaves every channel unchanged.
+    /// Preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, Ratio, rgb, rgba};
+    ///
+    /// let invert = |c: Ratio| Ratio::from_u8(255 - c.as_u8());
+    /// let halve = |c: Ratio| c * 0.5;
+    ///
+    /// assert_eq!(rgb(250, 128, 114).map_rgb(invert), rgb(5, 127, 141));
+    /// assert_eq!(rgba(250, 128, 114, 0.5).map_rgb(halve), rgba(125, 64, 57, 0.5));
+    /// ```
+    fn map_rgb(self, f: impl Fn(Ratio) -> Ratio) -> Self;
+
+    /// Reduces each of the red, green and blue channels of `self` to `levels` evenly spaced
+    /// values, rounding every channel to the nearest one, for retro and pixel-art effects.
+    /// With 2 levels, every channel becomes either `0` or `255`; a single level (or none) behaves
+    /// the same. With 256 levels or more, every channel is left unchanged.
+    /// Colors in the HSL color model are posterized through [`Color::map_rgb`], so they keep
+    /// their color model. Preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(240, 100, 30).posterize(4), rgb(255, 85, 0));
+    /// assert_eq!(rgba(240, 100, 30, 0.5).posterize(2), rgba(255, 0, 0, 0.5));
+    /// assert_eq!(rgb(240, 100, 30).posterize(256), rgb(240, 100, 30));
+    /// ```
+    fn posterize(self, levels: u16) -> Self {
+        let steps = (levels.clamp(2, 256) - 1) as f32;
+
+        self.map_rgb(|c| Ratio::from_f32(round(c.as_f32() * steps) / steps))
+    }
+
+    /// Warms `self` up by shifting its hue and chroma towards amber (`rgb(255, 160, 64)`)
+    /// by `amount`, while keeping its perceived lightness. A `100%` warm color has the
+    /// hue and chroma of amber at the original lightness. Unlike [`Color::spin`], the
+    /// shift moves every color towards the same point rather than rotating all hues
+    /// equally. The shift is done in the [Oklab](https://bottosson.github.io/posts/oklab/)
+    /// color space, and any existing alpha channel is preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, percent};
+    ///
+    /// assert_eq!(rgb(128, 128, 128).warm(percent(20)), rgb(142, 124, 110));
+    /// assert_eq!(rgb(128, 128, 128).warm(percent(0)), rgb(128, 128, 128));
+    /// ```
+    fn warm(self, amount: Ratio) -> Self;
+
+    /// Cools `self` down by shifting its hue and chroma towards sky blue
+    /// (`rgb(64, 160, 255)`) by `amount`, while keeping its perceived lightness. This is
+    /// the counterpart of [`Color::warm`], and works the same way.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, percent};
+    ///
+    /// assert_eq!(rgb(128, 128, 128).cool(percent(20)), rgb(114, 130, 148));
+    /// assert_eq!(rgb(128, 128, 128).cool(percent(0)), rgb(128, 128, 128));
+    /// ```
+    fn cool(self, amount: Ratio) -> Self;
+
+    /// Multiplies each RGB channel of `self` with that of `other`, ignoring any alpha channel.
+    /// The result is always at least as dark as either color: multiplying by white leaves
+    /// a color unchanged, while multiplying by black gives black.
+    /// For more, see Less' [Color Blending](http://lesscss.org/functions/#color-blending-multiply).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 102, 0).multiply(rgb(51, 51, 51)), rgb(51, 20, 0));
+    /// assert_eq!(rgb(255, 102, 0).multiply(rgb(255, 255, 255)), rgb(255, 102, 0));
+    /// ```
+    fn multiply<T: Color>(self, other: T) -> RGB {
+        blend(self.to_rgb(), other.to_rgb(), |lhs, rhs| lhs * rhs)
+    }
+
+    /// Inverts each RGB channel of `self` and `other`, multiplies them, and inverts the result,
+    /// ignoring any alpha channel. This is the opposite of [`Color::multiply`]: the result is
+    /// always at least as light as either color, and screening with black leaves a color unchanged.
+    /// For more, see Less' [Color Blending](http://lesscss.org/functions/#color-blending-screen).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 102, 0).screen(rgb(51, 51, 51)), rgb(255, 133, 51));
+    /// assert_eq!(rgb(255, 102, 0).screen(rgb(0, 0, 0)), rgb(255, 102, 0));
+    /// ```
+    fn screen<T: Color>(self, other: T) -> RGB {
+        blend(self.to_rgb(), other.to_rgb(), |lhs, rhs| {
+            1.0 - (1.0 - lhs) * (1.0 - rhs)
+        })
+    }
+
+    /// Combines [`Color::multiply`] and [`Color::screen`], using `self` as the base layer:
+    /// channels of `self` darker than 50% are multiplied with `other`, while lighter ones are
+    /// screened, so dark parts of `self` get darker and light parts get lighter.
+    /// Any alpha channel is ignored.
+    /// For more, see Less' [Color Blending](http://lesscss.org/functions/#color-blending-overlay).
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 102, 0).overlay(rgb(51, 51, 51)), rgb(255, 41, 0));
+    /// assert_eq!(rgb(255, 102, 0).overlay(rgb(0, 0, 0)), rgb(255, 0, 0));
+    /// ```
+    fn overlay<T: Color>(self, other: T) -> RGB {
+        blend(self.to_rgb(), other.to_rgb(), |lhs, rhs| {
+            if lhs < 0.5 {
+                2.0 * lhs * rhs
+            } else {
+                1.0 - 2.0 * (1.0 - lhs) * (1.0 - rhs)
+            }
+        })
+    }
+
+    /// Simulates how `self` appears to someone with the color vision deficiency `kind`,
+    /// by projecting its cone responses onto the colors they can distinguish. The
+    /// simulation is done in linear light, following "Digital video colourmaps for checking
+    /// the legibility of displays by dichromats" by Viénot, Brettel and Mollon.
+    /// Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, CvdKind, rgb};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).simulate_cvd(CvdKind::Protanopia), rgb(94, 94, 13));
+    /// assert_eq!(rgb(255, 255, 255).simulate_cvd(CvdKind::Tritanopia), rgb(255, 255, 255));
+    /// ```
+    fn simulate_cvd(self, kind: CvdKind) -> RGB {
+        cvd::simulate(self.to_rgb(), kind)
+    }
+
+    /// Computes the [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+    /// of `self` as defined by WCAG, from `0.0` for black to `1.0` for white.
+    /// Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(0, 0, 0).luminance(), 0.0);
+    /// assert!((rgb(255, 255, 255).luminance() - 1.0).abs() < 0.001);
+    /// assert!(rgb(0, 255, 0).luminance() > rgb(255, 0, 0).luminance());
+    /// ```
+    fn luminance(self) -> f32 {
+        let RGB { r, g, b } = self.to_rgb();
+
+        0.2126 * to_linear(r.as_f32())
+            + 0.7152 * to_linear(g.as_f32())
+            + 0.0722 * to_linear(b.as_f32())
+    }
+
+    /// Computes the [contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between
+    /// `self` and `other` as defined by WCAG, from `1.0` for identical luminances up to `21.0`
+    /// for black against white. The order of the colors doesn't matter. WCAG asks for at least
+    /// `4.5` between normal text and its background. Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// assert!((rgb(0, 0, 0).contrast_ratio(rgb(255, 255, 255)) - 21.0).abs() < 0.001);
+    /// assert_eq!(rgb(250, 128, 114).contrast_ratio(rgb(250, 128, 114)), 1.0);
+    /// assert!(rgb(118, 118, 118).contrast_ratio(rgb(255, 255, 255)) > 4.5);
+    /// ```
+    fn contrast_ratio<T: Color>(self, other: T) -> f32 {
+        let lhs = self.luminance();
+        let rhs = other.luminance();
+
+        (lhs.max(rhs) + 0.05) / (lhs.min(rhs) + 0.05)
+    }
+
+    /// Computes the [`Color::contrast_ratio`] of `self` against black and against white, in
+    /// that order. Handy to decide whether black or white text reads better on `self`, while
+    /// still having the numbers at hand to show them. Any alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb};
+    ///
+    /// let (on_black, on_white) = rgb(250, 128, 114).text_contrast_report();
+    ///
+    /// assert!(on_black > 4.5);
+    /// assert!(on_white < 4.5);
+    /// ```
+    fn text_contrast_report(self) -> (f32, f32) {
+        let color = self.to_rgb();
+
+        (
+            color.contrast_ratio(RGB::BLACK),
+            color.contrast_ratio(RGB::WHITE),
+        )
+    }
+
+    /// Adjusts the HSL lightness of `self` until its [`Color::contrast_ratio`] against
+    /// `background` is at least `min_ratio`, keeping its hue and saturation.
+    /// Returns `self` unchanged if it already has enough contrast. Otherwise, both lightening
+    /// and darkening are tried, and the color needing the smaller change in lightness wins.
+    /// If neither can reach `min_ratio`, the result is whichever of the lightest (white) and
+    /// darkest (black) versions of `self` has more contrast. Preserves any existing alpha channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let text = hsl(200, 80, 60).ensure_contrast(white, 4.5);
+    ///
+    /// assert_eq!(text.to_string(), "hsl(200, 80%, 39%)");
+    /// assert!(text.contrast_ratio(white) >= 4.5);
+    /// assert_eq!(rgb(250, 128, 114).ensure_contrast(white, 30.0), rgb(0, 0, 0));
+    /// ```
+    fn ensure_contrast<T: Color>(self, background: T, min_ratio: f32) -> Self;
+
+    /// Measures how different `self` and `other` look, using the
+    /// [CIEDE2000](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) color difference
+    /// formula on the colors' CIE L*a*b* representations. Any alpha channel is ignored.
+    ///
+    /// A distance of `0.0` means the colors are identical, while a distance below `1.0`
+    /// is generally imperceptible. Black and white are `100.0` apart.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    /// let salmon = rgb(250, 128, 114);
+    ///
+    /// assert_eq!(tomato.distance(tomato), 0.0);
+    /// assert!(tomato.distance(salmon) < tomato.distance(hsl(200, 100, 50)));
+    /// ```
+    fn distance<T: Color>(self, other: T) -> f32 {
+        Lab::from_rgb(self.to_rgb()).delta_e(Lab::from_rgb(other.to_rgb()))
+    }
+
+    /// Measures the angle between the HSL hues of `self` and `other`, going whichever way round
+    /// the color wheel is shorter, so the result lies within `0-180°`.
+    /// Handy to group a palette into families of hues.
+    ///
+    /// Greys have no meaningful hue, so when either color is grey (as told by
+    /// [`Color::is_grayscale`]), the colors are considered as far apart as can be, at `180°`.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, deg, rgb, hsl};
+    ///
+    /// assert_eq!(hsl(0, 100, 50).hue_distance(hsl(30, 100, 50)), deg(30));
+    /// assert_eq!(hsl(350, 100, 50).hue_distance(hsl(20, 100, 50)), deg(30));
+    /// assert_eq!(rgb(255, 0, 0).hue_distance(rgb(0, 255, 255)), deg(180));
+    /// assert_eq!(rgb(255, 0, 0).hue_distance(rgb(128, 128, 128)), deg(180));
+    /// ```
+    fn hue_distance<T: Color>(self, other: T) -> Angle {
+        let lhs = self.to_hsl();
+        let rhs = other.to_hsl();
+
+        if lhs.is_grayscale() || rhs.is_grayscale() {
+            return deg(180);
         }
+
+        let difference = (lhs.h.degrees() as i32 - rhs.h.degrees() as i32).abs();
+
+        deg(difference.min(360 - difference))
+    }
+
+    /// Finds the [CSS named color](https://www.w3.org/TR/css-color-4/#named-colors)
+    /// closest to `self`, as measured by [`Color::distance`]. Any alpha channel is ignored.
+    ///
+    /// When several names share the same color (e.g. `aqua` and `cyan`), the
+    /// alphabetically first one is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use farver::{Color, rgb, hsl};
+    ///
+    /// assert_eq!(rgb(100, 149, 237).nearest_named(), "cornflowerblue");
+    /// assert_eq!(rgb(250, 125, 110).nearest_named(), "salmon");
+    /// assert_eq!(hsl(180, 100, 50).nearest_named(), "aqua");
+    /// ```
+    fn nearest_named(self) -> &'static str {
+        let lab = Lab::from_rgb(self.to_rgb());
+
+        NAMED_COLORS
+            .iter()
+            .map(|&(name, color)| (name, lab.delta_e(Lab::from_rgb(color))))
+            .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+            .map(|(name, _)| name)
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "ansi_term")]
+    fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
+        &self,
+        input: I,
+    ) -> ansi_term::ANSIGenericString<'a, S>
+    where
+        I: Into<Cow<'a, S>>,
+        <S as ToOwned>::Owned: Debug;
+}
+
+/// Declares each of `colors` as a CSS custom property within a `:root` block, one per
+/// line, using [`Color::to_css_var`] with the name it's paired with.
+///
+/// # Example
+/// ```
+/// use farver::{rgb, to_css_vars};
+///
+/// let theme = [("background", rgb(255, 255, 255)), ("accent", rgb(250, 128, 114))];
+///
+/// assert_eq!(
+///     to_css_vars(&theme),
+///     ":root {\n  --background: rgb(255, 255, 255);\n  --accent: rgb(250, 128, 114);\n}\n"
+/// );
+/// ```
+pub fn to_css_vars<T: Color + Copy>(colors: &[(&str, T)]) -> String {
+    let mut css = String::from(":root {\n");
+
+    for &(name, color) in colors {
+        css.push_str("  ");
+        css.push_str(&color.to_css_var(name));
+        css.push('\n');
     }
 
-    impl ApproximatelyEq for HSLA {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.h.approximately_eq(other.h)
-                    && self
-                        .s
-                        .as_percentage()
-                        .approximately_eq(other.s.as_percentage())
-                    && self
-                        .l
-                        .as_percentage()
-                        .approximately_eq(other.l.as_percentage())
-                    && self.a == other.a
+    css.push_str("}\n");
+    css
+}
+
+/// Joins the [`Color::to_css`] forms of `colors` into a comma separated list, such as to
+/// print a palette in logs or debug output.
+///
+/// # Example
+/// ```
+/// use farver::{palette_to_css, rgb};
+///
+/// let palette = [rgb(255, 255, 255), rgb(250, 128, 114)];
+///
+/// assert_eq!(palette_to_css(&palette), "rgb(255, 255, 255), rgb(250, 128, 114)");
+/// ```
+pub fn palette_to_css<T: Color + Copy>(colors: &[T]) -> String {
+    join_palette(colors, |color, css| color.write_css(css))
+}
+
+/// Joins the [`Color::to_hex`] forms of `colors` into a comma separated list, like
+/// [`palette_to_css`].
+///
+/// # Example
+/// ```
+/// use farver::{palette_to_hex, rgba};
+///
+/// let palette = [rgba(255, 255, 255, 1.0), rgba(250, 128, 114, 0.5)];
+///
+/// assert_eq!(palette_to_hex(&palette), "#ffffffff, #fa807280");
+/// ```
+pub fn palette_to_hex<T: Color + Copy>(colors: &[T]) -> String {
+    join_palette(colors, |color, hex| color.write_hex(hex))
+}
+
+// Writes each of `colors` with `write`, separated by commas.
+fn join_palette<T: Copy>(colors: &[T], write: impl Fn(T, &mut String) -> fmt::Result) -> String {
+    let mut joined = String::new();
+
+    for (index, &color) in colors.iter().enumerate() {
+        if index > 0 {
+            joined.push_str(", ");
         }
+
+        write(color, &mut joined).expect("writing to a String can't fail");
     }
 
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approx::ApproximatelyEq;
+    use crate::fine::{FineAngle, FineRatio};
+    use crate::lab::{Lab, Oklab};
+    use crate::{
+        average, average_linear, deg, hsl, hsl_to_rgb, hsla, mix_all, palette_to_css,
+        palette_to_hex, percent, rgb, rgb_to_hsl, rgba, to_css_vars, Angle, Channel, Color, Ratio,
+        HSL, HSLA, RGB, RGBA,
+    };
+
     macro_rules! assert_approximately_eq {
         ($lhs:expr, $rhs:expr) => {
             let lhs = $lhs;
             let rhs = $rhs;
 
-            assert!(lhs.approximately_eq(rhs), "lhs: {}, rhs: {}", lhs, rhs);
+            assert!(lhs.approximately_eq(rhs, 1), "lhs: {}, rhs: {}", lhs, rhs);
         };
     }
 
@@ -421,17 +1564,17 @@ mod tests {
         assert_eq!(
             hsl(6, 93, 71),
             HSL {
-                h: Angle::new(6),
-                s: Ratio::from_percentage(93),
-                l: Ratio::from_percentage(71)
+                h: Angle::new(6).into(),
+                s: FineRatio::from_percentage(93),
+                l: FineRatio::from_percentage(71)
             }
         );
         assert_eq!(
             hsla(6, 93, 71, 1.0),
             HSLA {
-                h: Angle::new(6),
-                s: Ratio::from_percentage(93),
-                l: Ratio::from_percentage(71),
+                h: Angle::new(6).into(),
+                s: FineRatio::from_percentage(93),
+                l: FineRatio::from_percentage(71),
                 a: Ratio::from_u8(255),
             }
         );
@@ -576,6 +1719,32 @@ mod tests {
                             rgba($r, $g, $b, 0.0)
                         );
                     }
+
+                    // An `HSL` color written in whole percentages may not be exactly
+                    // representable in `RGB`, but a single round trip settles it.
+                    #[test]
+                    fn hsl_round_trip_is_idempotent() {
+                        let once = hsl($h, $s, $l).to_rgb().to_hsl();
+
+                        assert_eq!(once.to_rgb().to_hsl(), once);
+                        assert_approximately_eq!(once, hsl($h, $s, $l));
+                    }
+
+                    #[test]
+                    fn rgb_round_trip_is_idempotent() {
+                        assert_eq!(rgb($r, $g, $b).to_hsl().to_rgb(), rgb($r, $g, $b));
+                        assert_eq!(
+                            rgba($r, $g, $b, 0.78).to_hsla().to_rgba(),
+                            rgba($r, $g, $b, 0.78)
+                        );
+                    }
+
+                    #[test]
+                    fn hsla_round_trip_is_idempotent() {
+                        let once = hsla($h, $s, $l, 0.78).to_rgba().to_hsla();
+
+                        assert_eq!(once.to_rgba().to_hsla(), once);
+                    }
                 }
             };
         }
@@ -599,8 +1768,14 @@ mod tests {
     }
 
     #[test]
-    fn can_saturate() {
-        assert_approximately_eq!(hsl(9, 35, 50).saturate(percent(20)), hsl(9, 55, 50));
+    fn converts_nearly_saturated_colors_without_panicking() {
+        assert_eq!(rgb(1, 1, 255).to_hsl().to_css(), "hsl(240, 100%, 50%)");
+        assert_eq!(rgb(254, 0, 0).to_hsl().to_css(), "hsl(0, 100%, 50%)");
+    }
+
+    #[test]
+    fn can_saturate() {
+        assert_approximately_eq!(hsl(9, 35, 50).saturate(percent(20)), hsl(9, 55, 50));
         assert_approximately_eq!(
             hsla(9, 35, 50, 1.0).saturate(percent(20)),
             hsla(9, 55, 50, 1.0)
@@ -655,6 +1830,274 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_lighten_and_darken_past_bounds() {
+        assert_eq!(hsl(9, 35, 95).lighten(percent(90)), hsl(9, 35, 100));
+        assert_eq!(
+            hsla(9, 35, 95, 0.5).lighten(percent(100)),
+            hsla(9, 35, 100, 0.5)
+        );
+        assert_eq!(hsl(9, 35, 5).darken(percent(90)), hsl(9, 35, 0));
+        assert_eq!(
+            hsla(9, 35, 5, 0.5).darken(percent(100)),
+            hsla(9, 35, 0, 0.5)
+        );
+
+        assert_eq!(rgb(250, 240, 238).lighten(percent(90)), rgb(255, 255, 255));
+        assert_eq!(
+            rgba(250, 240, 238, 0.5).lighten(percent(100)),
+            rgba(255, 255, 255, 0.5)
+        );
+        assert_eq!(rgb(12, 5, 4).darken(percent(90)), rgb(0, 0, 0));
+        assert_eq!(rgba(12, 5, 4, 0.5).darken(percent(100)), rgba(0, 0, 0, 0.5));
+
+        assert_eq!(hsl(9, 35, 100).lighten(percent(100)), hsl(9, 35, 100));
+        assert_eq!(hsl(9, 35, 0).darken(percent(100)), hsl(9, 35, 0));
+        assert_eq!(hsl(9, 95, 50).saturate(percent(90)), hsl(9, 100, 50));
+        assert_eq!(hsl(9, 5, 50).desaturate(percent(90)), hsl(9, 0, 50));
+    }
+
+    #[test]
+    fn can_saturate_relative() {
+        assert_eq!(
+            hsl(120, 25, 50).saturate_relative(percent(100)),
+            hsl(120, 50, 50)
+        );
+        assert_eq!(
+            hsla(120, 25, 50, 0.3).saturate_relative(percent(100)),
+            hsla(120, 50, 50, 0.3)
+        );
+        assert_eq!(
+            hsl(120, 25, 50).saturate_relative(percent(0)),
+            hsl(120, 25, 50)
+        );
+
+        // Saturation stops at 100%, so vivid colors stay put.
+        assert_eq!(
+            hsl(120, 60, 50).saturate_relative(percent(100)),
+            hsl(120, 100, 50)
+        );
+        assert_eq!(
+            hsl(120, 100, 50).saturate_relative(percent(50)),
+            hsl(120, 100, 50)
+        );
+        assert_approximately_eq!(
+            rgb(0, 255, 0).saturate_relative(percent(50)),
+            rgb(0, 255, 0)
+        );
+
+        // Greys, whether RGB or HSL, can't be saturated into a color.
+        for grey in [rgb(0, 0, 0), rgb(128, 128, 128), rgb(255, 255, 255)] {
+            assert_eq!(grey.saturate_relative(Ratio::FULL), grey);
+            assert_eq!(
+                grey.fade(percent(50)).saturate_relative(Ratio::FULL),
+                grey.fade(percent(50))
+            );
+        }
+        assert_eq!(
+            hsl(300, 0, 40).saturate_relative(Ratio::FULL),
+            hsl(300, 0, 40)
+        );
+
+        // A near grey only gains as much color as it had to begin with.
+        let near_grey = rgb(128, 128, 129);
+        assert!(near_grey.saturate_relative(Ratio::FULL).to_hsl().s <= percent(1).into());
+    }
+
+    #[test]
+    fn can_desaturate_relative() {
+        assert_eq!(
+            hsl(120, 50, 50).desaturate_relative(percent(60)),
+            hsl(120, 20, 50)
+        );
+        assert_eq!(
+            hsla(120, 50, 50, 0.3).desaturate_relative(percent(60)),
+            hsla(120, 20, 50, 0.3)
+        );
+        assert_eq!(
+            hsl(120, 50, 50).desaturate_relative(percent(0)),
+            hsl(120, 50, 50)
+        );
+
+        // Only a full desaturation reaches grey, matching `greyscale`.
+        let green = rgb(30, 200, 60);
+        assert_eq!(green.desaturate_relative(Ratio::FULL), green.greyscale());
+        assert!(green.desaturate_relative(Ratio::FULL).is_grayscale());
+        assert!(!hsl(120, 100, 50)
+            .desaturate_relative(percent(90))
+            .is_grayscale());
+
+        // Repeated desaturations fade the color out gradually and keep its hue.
+        let mut faded = hsl(120, 100, 50);
+        for _ in 0..4 {
+            faded = faded.desaturate_relative(percent(50));
+        }
+        assert_eq!(faded.to_string(), "hsl(120, 6%, 50%)");
+
+        assert_eq!(
+            hsl(300, 0, 40).desaturate_relative(percent(50)),
+            hsl(300, 0, 40)
+        );
+    }
+
+    #[test]
+    fn can_lighten_relative() {
+        assert_approximately_eq!(hsl(9, 35, 40).lighten_relative(percent(25)), hsl(9, 35, 50));
+        assert_approximately_eq!(
+            hsla(9, 35, 40, 0.5).lighten_relative(percent(25)),
+            hsla(9, 35, 50, 0.5)
+        );
+        assert_approximately_eq!(
+            rgb(172, 96, 83).lighten_relative(percent(40)),
+            hsl(9, 35, 70).to_rgb()
+        );
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.5).lighten_relative(percent(40)),
+            hsla(9, 35, 70, 0.5).to_rgba()
+        );
+        assert_eq!(
+            hsl(9, 35, 80).lighten_relative(percent(50)),
+            hsl(9, 35, 100)
+        );
+    }
+
+    #[test]
+    fn can_darken_relative() {
+        assert_approximately_eq!(hsl(9, 35, 50).darken_relative(percent(20)), hsl(9, 35, 40));
+        assert_approximately_eq!(
+            hsla(9, 35, 50, 0.5).darken_relative(percent(20)),
+            hsla(9, 35, 40, 0.5)
+        );
+        assert_approximately_eq!(
+            rgb(172, 96, 83).darken_relative(percent(40)),
+            hsl(9, 35, 30).to_rgb()
+        );
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.5).darken_relative(percent(40)),
+            hsla(9, 35, 30, 0.5).to_rgba()
+        );
+        assert_eq!(hsl(9, 35, 80).darken_relative(percent(100)), hsl(9, 35, 0));
+    }
+
+    #[test]
+    fn can_scale_saturation() {
+        assert_approximately_eq!(hsl(9, 60, 50).scale_saturation(0.5), hsl(9, 80, 50));
+        assert_approximately_eq!(hsl(9, 60, 50).scale_saturation(-0.5), hsl(9, 30, 50));
+        assert_approximately_eq!(
+            hsla(9, 60, 50, 0.5).scale_saturation(0.25),
+            hsla(9, 70, 50, 0.5)
+        );
+        assert_approximately_eq!(
+            rgb(172, 96, 83).scale_saturation(-1.0),
+            hsl(9, 0, 50).to_rgb()
+        );
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.5).scale_saturation(1.0),
+            hsla(9, 100, 50, 0.5).to_rgba()
+        );
+        assert_eq!(hsl(9, 60, 50).scale_saturation(0.0), hsl(9, 60, 50));
+    }
+
+    #[test]
+    fn can_scale_lightness() {
+        assert_approximately_eq!(hsl(9, 35, 60).scale_lightness(0.5), hsl(9, 35, 80));
+        assert_approximately_eq!(hsl(9, 35, 60).scale_lightness(-0.5), hsl(9, 35, 30));
+        assert_approximately_eq!(
+            hsla(9, 35, 60, 0.5).scale_lightness(0.25),
+            hsla(9, 35, 70, 0.5)
+        );
+        assert_approximately_eq!(
+            rgb(172, 96, 83).scale_lightness(0.2),
+            hsl(9, 35, 60).to_rgb()
+        );
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.5).scale_lightness(-0.2),
+            hsla(9, 35, 40, 0.5).to_rgba()
+        );
+    }
+
+    #[test]
+    fn scaling_never_overshoots() {
+        assert_eq!(hsl(9, 35, 90).scale_lightness(1.0), hsl(9, 35, 100));
+        assert_eq!(hsl(9, 35, 90).scale_lightness(5.0), hsl(9, 35, 100));
+        assert_eq!(hsl(9, 35, 10).scale_lightness(-1.0), hsl(9, 35, 0));
+        assert_eq!(hsl(9, 35, 10).scale_lightness(-5.0), hsl(9, 35, 0));
+        assert_eq!(hsl(9, 95, 50).scale_saturation(1.0), hsl(9, 100, 50));
+        assert_eq!(hsl(9, 100, 50).scale_saturation(0.5), hsl(9, 100, 50));
+        assert_eq!(hsl(9, 0, 50).scale_saturation(-0.5), hsl(9, 0, 50));
+    }
+
+    #[test]
+    fn relative_lightness_is_proportional() {
+        // The same amount moves a dark color less than a light one when
+        // relative, but equally far when absolute.
+        let dark = hsl(9, 35, 20);
+        let light = hsl(9, 35, 60);
+
+        assert_approximately_eq!(dark.lighten(percent(20)), hsl(9, 35, 40));
+        assert_approximately_eq!(light.lighten(percent(20)), hsl(9, 35, 80));
+        assert_approximately_eq!(dark.lighten_relative(percent(20)), hsl(9, 35, 24));
+        assert_approximately_eq!(light.lighten_relative(percent(20)), hsl(9, 35, 72));
+
+        assert_approximately_eq!(dark.darken(percent(20)), hsl(9, 35, 0));
+        assert_approximately_eq!(light.darken(percent(20)), hsl(9, 35, 40));
+        assert_approximately_eq!(dark.darken_relative(percent(20)), hsl(9, 35, 16));
+        assert_approximately_eq!(light.darken_relative(percent(20)), hsl(9, 35, 48));
+    }
+
+    #[test]
+    fn clamps_saturation() {
+        assert_eq!(hsl(9, 95, 50).saturate(percent(20)), hsl(9, 100, 50));
+        assert_eq!(hsl(9, 100, 50).saturate(percent(100)), hsl(9, 100, 50));
+        assert_eq!(
+            hsla(9, 95, 50, 0.5).saturate(percent(20)),
+            hsla(9, 100, 50, 0.5)
+        );
+        assert_eq!(hsl(9, 5, 50).desaturate(percent(20)), hsl(9, 0, 50));
+        assert_eq!(hsl(9, 0, 50).desaturate(percent(100)), hsl(9, 0, 50));
+        assert_eq!(
+            hsla(9, 5, 50, 0.5).desaturate(percent(20)),
+            hsla(9, 0, 50, 0.5)
+        );
+
+        assert_approximately_eq!(rgb(255, 0, 0).saturate(percent(50)), rgb(255, 0, 0));
+        assert_approximately_eq!(
+            rgb(172, 96, 83).desaturate(percent(100)),
+            rgb(128, 128, 128)
+        );
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.5).desaturate(percent(100)),
+            rgba(128, 128, 128, 0.5)
+        );
+    }
+
+    #[test]
+    fn clamps_lightness() {
+        assert_eq!(hsl(9, 35, 90).lighten(percent(20)), hsl(9, 35, 100));
+        assert_eq!(hsl(9, 35, 100).lighten(percent(100)), hsl(9, 35, 100));
+        assert_eq!(
+            hsla(9, 35, 90, 0.5).lighten(percent(20)),
+            hsla(9, 35, 100, 0.5)
+        );
+        assert_eq!(hsl(9, 35, 10).darken(percent(20)), hsl(9, 35, 0));
+        assert_eq!(hsl(9, 35, 0).darken(percent(100)), hsl(9, 35, 0));
+        assert_eq!(
+            hsla(9, 35, 10, 0.5).darken(percent(20)),
+            hsla(9, 35, 0, 0.5)
+        );
+
+        assert_eq!(rgb(205, 160, 152).lighten(percent(90)), rgb(255, 255, 255));
+        assert_eq!(
+            rgba(205, 160, 152, 0.5).lighten(percent(90)),
+            rgba(255, 255, 255, 0.5)
+        );
+        assert_eq!(rgb(172, 96, 83).darken(percent(90)), rgb(0, 0, 0));
+        assert_eq!(
+            rgba(172, 96, 83, 0.5).darken(percent(90)),
+            rgba(0, 0, 0, 0.5)
+        );
+    }
+
     #[test]
     fn can_fadein() {
         assert_approximately_eq!(hsl(9, 35, 50).fadein(percent(25)), hsla(9, 35, 50, 1.0));
@@ -699,6 +2142,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_stack_alpha() {
+        let shadow = rgba(23, 98, 119, 0.2);
+
+        assert_eq!(shadow.stack_alpha(0), rgba(23, 98, 119, 0.0));
+        assert_eq!(shadow.stack_alpha(1), shadow);
+        assert_eq!(shadow.stack_alpha(3).to_css(), "rgba(23, 98, 119, 0.49)");
+        assert_eq!(shadow.stack_alpha(u32::MAX), rgba(23, 98, 119, 1.0));
+        assert_eq!(
+            rgba(23, 98, 119, 0.0).stack_alpha(5),
+            rgba(23, 98, 119, 0.0)
+        );
+        assert_eq!(rgb(23, 98, 119).stack_alpha(1), rgba(23, 98, 119, 1.0));
+        assert_eq!(rgb(23, 98, 119).stack_alpha(0), rgba(23, 98, 119, 0.0));
+        assert_approximately_eq!(
+            hsla(193, 67, 28, 0.5).stack_alpha(2),
+            hsla(193, 67, 28, 0.75)
+        );
+
+        for value in 0..=255 {
+            let color = rgba(1, 2, 3, value as f32 / 255.0);
+
+            assert_eq!(color.stack_alpha(1), color);
+        }
+    }
+
+    #[test]
+    fn can_fade_to_bounds() {
+        assert_eq!(rgba(1, 2, 3, 1.0).fade(percent(0)), rgba(1, 2, 3, 0.0));
+        assert_eq!(
+            rgba(1, 2, 3, 1.0).fade(percent(0)).to_css(),
+            "rgba(1, 2, 3, 0.00)"
+        );
+        assert_eq!(rgba(1, 2, 3, 0.0).fade(percent(100)), rgba(1, 2, 3, 1.0));
+        assert_eq!(
+            rgba(1, 2, 3, 0.0).fade(percent(100)).to_css(),
+            "rgba(1, 2, 3, 1.00)"
+        );
+        assert_eq!(rgb(1, 2, 3).fade(percent(0)).a, Ratio::from_u8(0));
+        assert_eq!(rgb(1, 2, 3).fade(percent(100)).a, Ratio::from_u8(255));
+        assert_eq!(hsl(6, 93, 71).fade(percent(0)), hsla(6, 93, 71, 0.0));
+        assert_eq!(
+            hsla(6, 93, 71, 0.0).fade(percent(100)),
+            hsla(6, 93, 71, 1.0)
+        );
+    }
+
+    #[test]
+    fn clamps_invalid_alpha_values() {
+        for (alpha, expected) in [(f32::NAN, 0), (2.0, 255), (-1.0, 0), (f32::INFINITY, 255)] {
+            assert_eq!(rgba(1, 2, 3, alpha).a, Ratio::from_u8(expected));
+            assert_eq!(hsla(6, 93, 71, alpha).a, Ratio::from_u8(expected));
+            assert_eq!(rgb(1, 2, 3).fade(alpha).a, Ratio::from_u8(expected));
+            assert_eq!(hsl(6, 93, 71).fade(alpha).a, Ratio::from_u8(expected));
+        }
+    }
+
+    #[test]
+    fn can_fadein_and_fadeout_to_bounds() {
+        assert_eq!(rgba(1, 2, 3, 0.25).fadein(percent(75)), rgba(1, 2, 3, 1.0));
+        assert_eq!(rgba(1, 2, 3, 0.5).fadein(percent(100)), rgba(1, 2, 3, 1.0));
+        assert_eq!(rgba(1, 2, 3, 0.75).fadeout(percent(75)), rgba(1, 2, 3, 0.0));
+        assert_eq!(rgba(1, 2, 3, 0.5).fadeout(percent(100)), rgba(1, 2, 3, 0.0));
+        assert_eq!(
+            rgb(1, 2, 3).fadeout(percent(100)).to_css(),
+            "rgba(1, 2, 3, 0.00)"
+        );
+        assert_eq!(
+            hsla(6, 93, 71, 0.5).fadein(percent(50)),
+            hsla(6, 93, 71, 1.0)
+        );
+        assert_eq!(hsl(6, 93, 71).fadeout(percent(100)), hsla(6, 93, 71, 0.0));
+    }
+
     #[test]
     fn can_spin_forward() {
         assert_approximately_eq!(rgb(75, 207, 23).spin(deg(100)), rgb(23, 136, 207));
@@ -721,6 +2238,27 @@ mod tests {
         assert_approximately_eq!(hsla(10, 90, 50, 1.0).spin(deg(-30)), hsla(340, 90, 50, 1.0));
     }
 
+    #[test]
+    fn wraps_hues_at_the_full_turn() {
+        let red = hsl(0, 100, 50);
+
+        for hue in [360, 720, -360] {
+            assert_eq!(hsl(hue, 100, 50), red);
+            assert_eq!(hsl(hue, 100, 50).to_rgb(), red.to_rgb());
+            assert_eq!(
+                hsla(hue, 100, 50, 0.5).to_rgba(),
+                red.fade(percent(50)).to_rgba()
+            );
+        }
+
+        assert_eq!(hsl(350, 100, 50).spin(deg(10)), red);
+        assert_eq!(hsl(350, 100, 50).spin(deg(10)).to_rgb(), red.to_rgb());
+        assert_eq!(hsl(10, 100, 50).spin(deg(-10)).to_rgb(), red.to_rgb());
+        assert_eq!(rgb(255, 0, 0).spin(deg(360)), rgb(255, 0, 0).spin(deg(0)));
+        assert_eq!(hsl_to_rgb(360.0, 1.0, 0.5), (1.0, 0.0, 0.0));
+        assert_eq!(hsl_to_rgb(360.0, 1.0, 0.5), hsl_to_rgb(0.0, 1.0, 0.5));
+    }
+
     #[test]
     fn can_mix() {
         let brown_rgba = rgba(50, 50, 0, 1.0);
@@ -742,129 +2280,1125 @@ mod tests {
     }
 
     #[test]
-    fn can_mix_single_color() {
-        let rgba_red = rgba(100, 0, 0, 1.0);
-        let rgba_green = rgba(0, 100, 0, 0.5);
-        let hsla_red = hsla(120, 100, 20, 1.0);
-        let hsla_green = hsla(0, 100, 20, 0.5);
-
-        assert_approximately_eq!(rgba_red.mix(rgba_green, percent(100)), rgba_red);
-        assert_approximately_eq!(rgba_red.mix(rgba_green, percent(0)), rgba_green);
-        assert_approximately_eq!(rgba_green.mix(rgba_red, percent(100)), rgba_green);
-        assert_approximately_eq!(rgba_green.mix(rgba_red, percent(0)), rgba_red);
-        assert_approximately_eq!(rgba_red.mix(rgba_green, percent(0)), rgba_green);
+    fn mixes_alike_whatever_the_weight_constructor() {
+        let salmon = hsl(6, 93, 71);
+        let navy = rgba(0, 0, 80, 0.5);
+
+        for percentage in 0..=100 {
+            let weight = percent(percentage);
+            let mixed = salmon.mix(navy, weight);
+
+            assert_eq!(Ratio::from_u8(weight.as_u8()), weight);
+            assert_eq!(salmon.mix(navy, Ratio::from_u8(weight.as_u8())), mixed);
+            assert_eq!(salmon.mix(navy, Ratio::from_percentage(percentage)), mixed);
+            assert_eq!(salmon.mix(navy, percentage as f32 / 100.0), mixed);
+        }
 
-        assert_approximately_eq!(hsla_red.mix(hsla_green, percent(100)), hsla_red);
-        assert_approximately_eq!(hsla_red.mix(hsla_green, percent(0)), hsla_green);
-        assert_approximately_eq!(hsla_green.mix(hsla_red, percent(100)), hsla_green);
-        assert_approximately_eq!(hsla_green.mix(hsla_red, percent(0)), hsla_red);
-        assert_approximately_eq!(hsla_red.mix(hsla_green, percent(0)), hsla_green);
+        assert_eq!(percent(50), Ratio::from_u8(128));
+        assert_eq!(
+            rgb(250, 128, 114).mix(navy, percent(50)),
+            rgb(250, 128, 114).mix(navy, Ratio::from_u8(128))
+        );
     }
 
     #[test]
-    fn can_mix_with_alpha() {
-        let red_rgba = rgba(100, 0, 0, 1.0);
-        let green_rgba = rgba(0, 100, 0, 0.5);
-        let brown_rgba = rgba(75, 25, 0, 0.75);
-        let green_hsla = hsla(120, 100, 20, 1.0);
-        let red_hsla = hsla(0, 100, 20, 1.0);
-        let brown_hsla = hsla(60, 100, 10, 1.0);
+    fn clamps_mix_weights_above_full() {
+        let salmon = hsl(6, 93, 71);
+        let navy = rgba(0, 0, 80, 0.5);
 
-        assert_approximately_eq!(red_rgba.mix(green_rgba, percent(50)), brown_rgba);
-        assert_approximately_eq!(green_rgba.mix(red_rgba, percent(50)), brown_rgba);
-        assert_approximately_eq!(red_hsla.mix(green_hsla, percent(50)), brown_hsla);
-        assert_approximately_eq!(green_hsla.mix(red_hsla, percent(50)), brown_hsla);
+        assert_eq!(
+            salmon.mix(navy, percent(150)),
+            salmon.mix(navy, percent(100))
+        );
+        assert_approximately_eq!(salmon.mix(navy, percent(150)), salmon.to_hsla());
+        assert_eq!(salmon.tint(percent(150)), salmon.tint(percent(100)));
+        assert_eq!(salmon.shade(percent(150)), salmon.shade(percent(100)));
+        assert_eq!(
+            rgb(250, 128, 114).mix(navy, percent(100) + percent(50)),
+            rgba(250, 128, 114, 1.0)
+        );
     }
 
     #[test]
-    fn can_tint() {
-        assert_approximately_eq!(
-            rgba(0, 0, 255, 0.5).tint(percent(50)),
-            rgba(191, 191, 255, 0.75)
-        );
-        assert_approximately_eq!(rgb(0, 0, 255).tint(percent(50)), rgb(128, 128, 255));
-        assert_approximately_eq!(hsl(6, 93, 71).tint(percent(50)), hsl(6, 92, 85));
+    fn can_lerp() {
+        let salmon = rgba(250, 128, 114, 1.0);
+        let navy = rgba(0, 0, 80, 0.2);
+
+        assert_eq!(salmon.lerp(navy, percent(0)), salmon);
+        assert_eq!(salmon.lerp(navy, percent(100)), navy);
+        assert_eq!(salmon.lerp(navy, 0.5), rgba(125, 64, 97, 0.6));
+        assert_eq!(rgb(250, 128, 114).lerp(navy, 0.5), rgba(125, 64, 97, 0.6));
         assert_approximately_eq!(
-            hsla(6, 93, 71, 0.5).tint(percent(50)),
-            hsla(6, 95, 93, 0.75)
+            hsl(6, 93, 71).lerp(navy, 0.5),
+            rgba(125, 64, 97, 0.6).to_hsla()
         );
     }
 
     #[test]
-    fn can_shade() {
-        assert_approximately_eq!(
-            rgba(0, 0, 255, 0.5).shade(percent(50)),
-            rgba(0, 0, 64, 0.75)
-        );
-        assert_approximately_eq!(rgb(0, 0, 255).shade(percent(50)), rgb(0, 0, 128));
-        assert_approximately_eq!(hsl(6, 93, 71).shade(percent(50)), hsl(6, 38, 36));
-        assert_approximately_eq!(
-            hsla(6, 93, 71, 0.5).shade(percent(50)),
-            hsla(7, 38, 18, 0.75)
-        );
+    fn lerp_matches_mix_for_equal_alphas() {
+        let salmon = rgb(250, 128, 114);
+        let navy = rgb(0, 0, 80);
+
+        for weight in [0, 10, 25, 50, 75, 100] {
+            assert_approximately_eq!(
+                salmon.lerp(navy, percent(weight)),
+                salmon.mix(navy, percent(100 - weight))
+            );
+        }
     }
 
     #[test]
-    fn can_greyscale() {
-        assert_approximately_eq!(rgb(128, 242, 13).greyscale(), rgb(128, 128, 128));
-        assert_approximately_eq!(
-            rgba(128, 242, 13, 1.0).greyscale(),
-            rgba(128, 128, 128, 1.0)
+    fn accepts_fractional_weights() {
+        let salmon = hsl(6, 93, 71);
+        let navy = rgba(0, 0, 80, 0.5);
+
+        assert_eq!(salmon.mix(navy, 0.5), salmon.mix(navy, percent(50)));
+        assert_eq!(salmon.mix_rgb(navy, 0.3), salmon.mix_rgb(navy, percent(30)));
+        assert_eq!(salmon.mix_hsl(navy, 0.3), salmon.mix_hsl(navy, percent(30)));
+        assert_eq!(
+            salmon.mix_perceptual(navy, 0.3),
+            salmon.mix_perceptual(navy, percent(30))
         );
-        assert_approximately_eq!(hsl(90, 90, 50).greyscale(), hsl(90, 0, 50));
-        assert_approximately_eq!(hsla(90, 90, 50, 1.0).greyscale(), hsla(90, 0, 50, 1.0));
+        assert_eq!(salmon.tint(0.1), salmon.tint(percent(10)));
+        assert_eq!(salmon.shade(0.1), salmon.shade(percent(10)));
+        assert_eq!(navy.fade(0.75), navy.fade(percent(75)));
+        assert_eq!(salmon.mix(navy, 1.5), salmon.mix(navy, percent(100)));
+        assert_eq!(salmon.mix(navy, -0.5), salmon.mix(navy, percent(0)));
     }
 
     #[test]
-    fn can_clone() {
-        let rgb_color = rgb(5, 10, 15);
-        let rgba_color = rgba(5, 10, 15, 1.0);
-        let hsl_color = hsl(6, 93, 71);
-        let hsla_color = hsla(6, 93, 71, 1.0);
+    fn mixed_model_results_are_stable() {
+        let red_hsl = hsl(10, 90, 50);
+        let navy_rgb = rgba(0, 0, 80, 1.0);
 
-        assert_eq!(rgb_color, rgb_color.clone());
-        assert_eq!(rgba_color, rgba_color.clone());
-        assert_eq!(hsl_color, hsl_color.clone());
-        assert_eq!(hsla_color, hsla_color.clone());
+        let mixed = red_hsl.mix(navy_rgb, percent(50));
+
+        assert_approximately_eq!(mixed.to_rgba(), navy_rgb.mix(red_hsl, percent(50)));
+        assert_approximately_eq!(mixed.to_rgba(), red_hsl.mix_rgb(navy_rgb, percent(50)));
+        assert_eq!(mixed.to_rgba().to_hsla().to_rgba(), mixed.to_rgba());
     }
 
     #[test]
-    fn can_copy() {
-        let rgb_color = rgb(172, 95, 82);
-        let rgba_color = rgba(172, 95, 82, 1.0);
-        let hsl_color = hsl(9, 35, 50);
-        let hsla_color = hsla(9, 35, 50, 1.0);
+    fn accents_stand_apart_from_their_base() {
+        assert_eq!(hsl(210, 60, 30).accent(), hsl(240, 60, 50));
+        assert_eq!(hsl(350, 60, 50).accent(), hsl(20, 60, 30));
+        assert_eq!(hsla(0, 0, 10, 0.25).accent(), hsla(30, 0, 30, 0.25));
+        assert_eq!(hsl(90, 40, 95).accent(), hsl(120, 40, 75));
 
-        let copied_rgb_color = rgb_color;
-        let copied_rgba_color = rgba_color;
-        let copied_hsl_color = hsl_color;
-        let copied_hsla_color = hsla_color;
+        let salmon = rgba(250, 128, 114, 0.5);
 
-        assert_eq!(rgb_color, copied_rgb_color);
-        assert_eq!(rgba_color, copied_rgba_color);
-        assert_eq!(hsl_color, copied_hsl_color);
-        assert_eq!(hsla_color, copied_hsla_color);
+        assert_eq!(salmon.accent(), salmon.to_hsla().accent().to_rgba());
+        assert_eq!(salmon.to_rgb().accent(), salmon.accent().to_rgb());
+        assert!(salmon.distance(salmon.accent()) > 10.0);
     }
 
     #[test]
-    fn can_debug() {
-        let rgb_value = format!("{:?}", rgb(5, 10, 15));
-        let rgba_value = format!("{:?}", rgba(5, 10, 15, 1.0));
-        let hsl_value = format!("{:?}", hsl(6, 93, 71));
-        let hsla_value = format!("{:?}", hsla(6, 93, 71, 1.0));
+    fn shades_ramp_from_light_to_dark() {
+        let base = hsla(217, 91, 60, 0.5);
+        let shades = base.shades();
 
-        assert_eq!(rgb_value, "RGB { r: Ratio(5), g: Ratio(10), b: Ratio(15) }");
-        assert_eq!(
-            rgba_value,
-            "RGBA { r: Ratio(5), g: Ratio(10), b: Ratio(15), a: Ratio(255) }"
-        );
-        assert_eq!(
-            hsl_value,
-            "HSL { h: Angle { degrees: 6 }, s: Ratio(237), l: Ratio(181) }"
-        );
-        assert_eq!(
+        assert_eq!(shades[5], base.to_rgb());
+        assert_eq!(shades[2], base.to_rgb().tint(percent(40)));
+        assert_eq!(shades[8], base.to_rgb().shade(percent(40)));
+
+        for pair in shades.windows(2) {
+            assert!(Lab::from_rgb(pair[0]).l > Lab::from_rgb(pair[1]).l);
+        }
+
+        assert_eq!(rgb(255, 255, 255).shades()[0], rgb(255, 255, 255));
+        assert_eq!(rgb(0, 0, 0).shades()[10], rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn can_mix_opaque() {
+        let red: RGB = rgb(100, 0, 0).mix_opaque(rgb(0, 100, 0), percent(50));
+        let red_hsl: HSL = hsl(0, 100, 20).mix_opaque(rgb(0, 100, 0), percent(50));
+
+        assert_approximately_eq!(red, rgb(50, 50, 0));
+        assert_approximately_eq!(red_hsl, rgb(50, 50, 0).to_hsl());
+
+        // Alpha channels don't shift the mix, and the result is fully opaque.
+        let faded: RGBA = rgba(100, 0, 0, 0.2).mix_opaque(rgba(0, 100, 0, 0.8), percent(50));
+        let faded_hsl: HSLA = hsla(0, 100, 20, 0.2).mix_opaque(rgb(0, 100, 0), percent(50));
+
+        assert_approximately_eq!(faded, rgba(50, 50, 0, 1.0));
+        assert_approximately_eq!(
+            faded_hsl.to_rgba(),
+            hsl(0, 100, 20).mix_rgb(rgb(0, 100, 0), percent(50))
+        );
+        assert_eq!(faded_hsl.a, percent(100));
+    }
+
+    #[test]
+    fn can_mix_rgb() {
+        let brown_rgba = rgba(50, 50, 0, 1.0);
+
+        assert_approximately_eq!(
+            rgba(100, 0, 0, 1.0).mix_rgb(rgba(0, 100, 0, 1.0), percent(50)),
+            brown_rgba
+        );
+        assert_approximately_eq!(
+            rgb(100, 0, 0).mix_rgb(rgb(0, 100, 0), percent(50)),
+            brown_rgba
+        );
+        assert_approximately_eq!(
+            hsl(0, 100, 20).mix_rgb(hsl(120, 100, 20), percent(50)),
+            brown_rgba
+        );
+        assert_approximately_eq!(
+            hsla(0, 100, 20, 1.0).mix_rgb(rgb(0, 100, 0), percent(50)),
+            brown_rgba
+        );
+    }
+
+    #[test]
+    fn can_mix_hsl() {
+        assert_approximately_eq!(
+            hsl(0, 100, 20).mix_hsl(hsl(120, 100, 40), percent(50)),
+            hsla(60, 100, 30, 1.0)
+        );
+        assert_approximately_eq!(
+            hsla(0, 100, 20, 1.0).mix_hsl(hsla(120, 100, 40, 0.0), percent(75)),
+            hsla(30, 100, 25, 0.75)
+        );
+        assert_approximately_eq!(
+            rgb(255, 0, 0).mix_hsl(rgb(0, 0, 255), percent(50)),
+            hsla(300, 100, 50, 1.0)
+        );
+    }
+
+    #[test]
+    fn mix_perceptual_avoids_muddy_midpoints() {
+        let red = rgb(255, 0, 0);
+        let lime = rgb(0, 255, 0);
+
+        let srgb = red.mix(lime, percent(50)).to_hsl();
+        let oklab = red.mix_perceptual(lime, percent(50)).to_hsl();
+
+        // The sRGB midpoint is a dark olive, while the Oklab one stays a bright yellow.
+        assert!(oklab.l > srgb.l);
+        assert!(oklab.s >= srgb.s);
+        assert!(
+            red.mix_perceptual(lime, percent(50))
+                .distance(rgb(255, 255, 0))
+                < srgb.distance(rgb(255, 255, 0))
+        );
+    }
+
+    #[test]
+    fn mix_perceptual_keeps_endpoints_and_alpha() {
+        let salmon = rgba(250, 128, 114, 1.0);
+        let teal = hsla(193, 68, 28, 0.0);
+
+        assert_eq!(salmon.mix_perceptual(teal, percent(100)), salmon);
+        assert_eq!(salmon.mix_perceptual(teal, percent(0)), teal.to_rgba());
+        assert_eq!(
+            salmon.mix_perceptual(teal, percent(50)).alpha_f32(),
+            128.0 / 255.0
+        );
+        assert_eq!(salmon.mix_perceptual(salmon, percent(30)), salmon);
+    }
+
+    #[test]
+    fn mix_subtractive_mixes_like_paint() {
+        let blue = rgb(0, 0, 255);
+        let yellow = rgb(255, 255, 0);
+        let green = blue.mix_subtractive(yellow, percent(50));
+
+        assert!(!green.to_rgb().is_grayscale());
+        assert!(green.g > green.r && green.g > green.b);
+        assert_approximately_eq!(green.to_hsla().h.to_angle(), deg(120));
+
+        assert_eq!(
+            rgb(255, 0, 0)
+                .mix_subtractive(yellow, percent(50))
+                .to_hsla()
+                .h
+                .to_angle(),
+            deg(30)
+        );
+        assert_eq!(
+            rgb(255, 0, 0)
+                .mix_subtractive(blue, percent(50))
+                .to_hsla()
+                .h
+                .to_angle(),
+            deg(300)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 0.5).mix_subtractive(blue, percent(100)),
+            rgba(250, 128, 114, 0.5)
+        );
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.2)
+                .mix_subtractive(rgba(0, 0, 80, 0.8), percent(50))
+                .a,
+            percent(50)
+        );
+    }
+
+    #[test]
+    fn mix_hsl_takes_the_shorter_hue_path() {
+        // Weights of 50% and 25% are 128/255 and 64/255, so the hues land a fraction of a
+        // degree off 10°.
+        assert_approximately_eq!(
+            hsl(350, 50, 50).mix_hsl(hsl(30, 50, 50), percent(50)),
+            hsla(10, 50, 50, 1.0)
+        );
+        assert_approximately_eq!(
+            hsl(30, 50, 50).mix_hsl(hsl(350, 50, 50), percent(50)),
+            hsla(10, 50, 50, 1.0)
+        );
+        assert_approximately_eq!(
+            hsl(340, 50, 50).mix_hsl(hsl(20, 50, 50), percent(25)),
+            hsla(10, 50, 50, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_composite_over() {
+        let white = rgb(255, 255, 255);
+
+        assert_eq!(rgba(255, 0, 0, 0.5).over(white), rgba(255, 127, 127, 1.0));
+        assert_approximately_eq!(hsla(0, 100, 50, 0.5).over(white), rgba(255, 127, 127, 1.0));
+        assert_eq!(
+            rgba(255, 0, 0, 0.5).over(rgba(0, 0, 255, 0.5)),
+            RGBA::from_u32(0xaa0055c0)
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 0.5).over(rgba(0, 0, 255, 0.0)),
+            rgba(255, 0, 0, 0.5)
+        );
+    }
+
+    #[test]
+    fn can_composite_over_with_bounds() {
+        let salmon = rgba(250, 128, 114, 0.5);
+
+        assert_eq!(rgba(255, 0, 0, 0.0).over(salmon), salmon);
+        assert_eq!(rgb(255, 0, 0).over(salmon), rgba(255, 0, 0, 1.0));
+        assert_eq!(
+            rgba(255, 0, 0, 1.0).over(rgb(0, 0, 255)),
+            rgba(255, 0, 0, 1.0)
+        );
+        assert_eq!(
+            rgba(255, 0, 0, 0.0).over(rgba(0, 0, 255, 0.0)),
+            rgba(0, 0, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn can_flatten() {
+        let white = rgb(255, 255, 255);
+
+        // An alpha of 0.5 is stored as 128/255, which lets slightly more of the
+        // foreground through than an exact half.
+        assert_eq!(rgba(0, 0, 0, 0.5).flatten(white), rgb(127, 127, 127));
+        assert_eq!(
+            hsla(0, 0, 0, 0.25).flatten(white),
+            rgba(0, 0, 0, 0.25).flatten(white)
+        );
+        assert_eq!(rgba(250, 128, 114, 1.0).flatten(white), rgb(250, 128, 114));
+        assert_eq!(rgba(250, 128, 114, 0.0).flatten(white), white);
+        assert_eq!(rgb(250, 128, 114).flatten(white), rgb(250, 128, 114));
+        assert_eq!(hsl(0, 0, 0).flatten(white), rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn can_blend_with_identities() {
+        let salmon = rgb(250, 128, 114);
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+
+        assert_eq!(salmon.multiply(white), salmon);
+        assert_eq!(salmon.multiply(black), black);
+        assert_eq!(salmon.screen(black), salmon);
+        assert_eq!(salmon.screen(white), white);
+        assert_eq!(white.overlay(salmon), white);
+        assert_eq!(black.overlay(salmon), black);
+    }
+
+    #[test]
+    fn can_multiply_and_screen() {
+        let grey = rgb(128, 128, 128);
+
+        assert_eq!(grey.multiply(grey), rgb(64, 64, 64));
+        assert_eq!(grey.screen(grey), rgb(192, 192, 192));
+        assert_eq!(
+            hsla(0, 100, 50, 0.5).multiply(rgba(0, 0, 0, 0.0)),
+            rgb(0, 0, 0)
+        );
+        assert_eq!(
+            rgb(255, 102, 0).multiply(rgb(51, 51, 51)),
+            rgb(51, 102, 0).multiply(rgb(255, 51, 51))
+        );
+    }
+
+    #[test]
+    fn can_overlay() {
+        assert_eq!(rgb(255, 102, 0).overlay(rgb(51, 51, 51)), rgb(255, 41, 0));
+        assert_eq!(rgb(64, 192, 0).overlay(rgb(128, 128, 128)), rgb(64, 192, 0));
+        assert_eq!(hsl(0, 0, 50).overlay(rgb(255, 0, 255)), rgb(255, 1, 255));
+    }
+
+    #[test]
+    fn can_mix_single_color() {
+        let rgba_red = rgba(100, 0, 0, 1.0);
+        let rgba_green = rgba(0, 100, 0, 0.5);
+        let hsla_red = hsla(120, 100, 20, 1.0);
+        let hsla_green = hsla(0, 100, 20, 0.5);
+
+        assert_approximately_eq!(rgba_red.mix(rgba_green, percent(100)), rgba_red);
+        assert_approximately_eq!(rgba_red.mix(rgba_green, percent(0)), rgba_green);
+        assert_approximately_eq!(rgba_green.mix(rgba_red, percent(100)), rgba_green);
+        assert_approximately_eq!(rgba_green.mix(rgba_red, percent(0)), rgba_red);
+        assert_approximately_eq!(rgba_red.mix(rgba_green, percent(0)), rgba_green);
+
+        assert_approximately_eq!(hsla_red.mix(hsla_green, percent(100)), hsla_red);
+        assert_approximately_eq!(hsla_red.mix(hsla_green, percent(0)), hsla_green);
+        assert_approximately_eq!(hsla_green.mix(hsla_red, percent(100)), hsla_green);
+        assert_approximately_eq!(hsla_green.mix(hsla_red, percent(0)), hsla_red);
+        assert_approximately_eq!(hsla_red.mix(hsla_green, percent(0)), hsla_green);
+    }
+
+    #[test]
+    fn can_set_alpha_from_f32() {
+        assert_eq!(rgb(1, 2, 3).with_alpha_f32(0.5), rgba(1, 2, 3, 0.5));
+        assert_eq!(rgba(1, 2, 3, 0.2).with_alpha_f32(0.5), rgba(1, 2, 3, 0.5));
+        assert_eq!(hsl(6, 93, 71).with_alpha_f32(0.25), hsla(6, 93, 71, 0.25));
+        assert_eq!(
+            hsla(6, 93, 71, 0.5).with_alpha_f32(2.0),
+            hsla(6, 93, 71, 1.0)
+        );
+        assert_eq!(rgb(1, 2, 3).with_alpha_f32(-1.0), rgba(1, 2, 3, 0.0));
+        assert_eq!(rgb(1, 2, 3).with_alpha_f32(f32::NAN), rgba(1, 2, 3, 0.0));
+    }
+
+    #[test]
+    fn can_mix_with_alpha() {
+        let red_rgba = rgba(100, 0, 0, 1.0);
+        let green_rgba = rgba(0, 100, 0, 0.5);
+        let brown_rgba = rgba(75, 25, 0, 0.75);
+        let green_hsla = hsla(120, 100, 20, 1.0);
+        let red_hsla = hsla(0, 100, 20, 1.0);
+        let brown_hsla = hsla(60, 100, 10, 1.0);
+
+        assert_approximately_eq!(red_rgba.mix(green_rgba, percent(50)), brown_rgba);
+        assert_approximately_eq!(green_rgba.mix(red_rgba, percent(50)), brown_rgba);
+        assert_approximately_eq!(red_hsla.mix(green_hsla, percent(50)), brown_hsla);
+        assert_approximately_eq!(green_hsla.mix(red_hsla, percent(50)), brown_hsla);
+    }
+
+    #[test]
+    fn rounds_chained_mixes_once_per_step() {
+        let white = rgba(254, 253, 252, 1.0);
+        let weight = percent(70);
+        let mut color = rgba(1, 2, 3, 1.0);
+        let mut expected = [1.0, 2.0, 3.0];
+
+        for _ in 0..8 {
+            color = color.mix(white, weight);
+
+            for (channel, target) in expected.iter_mut().zip([254.0, 253.0, 252.0]) {
+                *channel = *channel * weight.as_f32() + target * (1.0 - weight.as_f32());
+            }
+
+            let actual = [color.r, color.g, color.b].map(|c| c.as_u8() as f32);
+
+            for (actual, expected) in actual.iter().zip(expected) {
+                assert!((actual - expected).abs() <= 1.0, "{} drifted", color);
+            }
+        }
+    }
+
+    #[test]
+    fn can_mix_all() {
+        let red = rgba(100, 0, 0, 1.0);
+        let green = rgba(0, 100, 0, 0.0);
+        let blue = rgba(0, 0, 100, 0.5);
+
+        assert_eq!(mix_all(&[red], &[percent(10)]), Some(red));
+        assert_eq!(
+            mix_all(&[red, green], &[percent(50), percent(50)]),
+            Some(rgba(50, 50, 0, 0.5))
+        );
+        assert_approximately_eq!(
+            mix_all(
+                &[red, green, blue],
+                &[percent(20), percent(20), percent(20)]
+            )
+            .unwrap(),
+            rgba(33, 33, 33, 0.5)
+        );
+        assert_eq!(
+            mix_all(&[red, green, blue], &[percent(0), percent(100), percent(0)]),
+            Some(green)
+        );
+    }
+
+    #[test]
+    fn can_average() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(average([red]), Some(red));
+        assert_eq!(average([red, blue]), Some(rgb(128, 0, 128)));
+        assert_eq!(average([red, red, blue, blue]), Some(rgb(128, 0, 128)));
+        assert_eq!(average([red, red, red, blue]), Some(rgb(191, 0, 64)));
+        assert_eq!(
+            average(core::iter::repeat_n(rgb(250, 128, 114), 100_000)),
+            Some(rgb(250, 128, 114))
+        );
+        assert_eq!(average([]), None);
+    }
+
+    #[test]
+    fn can_average_in_linear_light() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(average_linear([red]), Some(red));
+        assert_eq!(average_linear([red, blue]), Some(rgb(188, 0, 188)));
+        assert_eq!(
+            average_linear([rgb(10, 20, 30), rgb(10, 20, 30)]),
+            Some(rgb(10, 20, 30))
+        );
+        assert_eq!(average_linear([]), None);
+
+        for value in 0..=255 {
+            let color = rgb(value, 255 - value, value / 2);
+
+            assert_eq!(average_linear([color, color, color]), Some(color));
+        }
+    }
+
+    #[test]
+    fn handles_invalid_mix_all_input() {
+        let red = rgba(100, 0, 0, 1.0);
+
+        assert_eq!(mix_all(&[], &[]), None);
+        assert_eq!(mix_all(&[red], &[]), None);
+        assert_eq!(mix_all(&[red], &[percent(50), percent(50)]), None);
+        assert_eq!(mix_all(&[red, red], &[percent(0), percent(0)]), None);
+    }
+
+    #[test]
+    fn can_tint() {
+        assert_approximately_eq!(
+            rgba(0, 0, 255, 0.5).tint(percent(50)),
+            rgba(191, 191, 255, 0.75)
+        );
+        assert_approximately_eq!(rgb(0, 0, 255).tint(percent(50)), rgb(128, 128, 255));
+        assert_approximately_eq!(hsl(6, 93, 71).tint(percent(50)), hsl(6, 92, 85));
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.5).tint(percent(50)),
+            hsla(6, 95, 93, 0.75)
+        );
+    }
+
+    #[test]
+    fn can_shade() {
+        assert_approximately_eq!(
+            rgba(0, 0, 255, 0.5).shade(percent(50)),
+            rgba(0, 0, 64, 0.75)
+        );
+        assert_approximately_eq!(rgb(0, 0, 255).shade(percent(50)), rgb(0, 0, 128));
+        assert_approximately_eq!(hsl(6, 93, 71).shade(percent(50)), hsl(6, 38, 36));
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.5).shade(percent(50)),
+            hsla(7, 38, 18, 0.75)
+        );
+    }
+
+    #[test]
+    fn lab_lightness_steps_are_uniform_across_hues() {
+        let yellow = hsl(60, 100, 40);
+        let blue = hsl(240, 100, 70);
+        let lightness = |c: HSL| Lab::from_rgb(c.to_rgb()).l;
+
+        let hsl_steps = (
+            lightness(yellow) - lightness(yellow.darken(percent(15))),
+            lightness(blue) - lightness(blue.darken(percent(15))),
+        );
+        let lab_steps = (
+            lightness(yellow) - lightness(yellow.darken_lab(percent(15))),
+            lightness(blue) - lightness(blue.darken_lab(percent(15))),
+        );
+
+        assert!((lab_steps.0 - lab_steps.1).abs() < (hsl_steps.0 - hsl_steps.1).abs());
+        assert!((lab_steps.0 - 15.0).abs() < 1.0);
+        assert!((lab_steps.1 - 15.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn lab_lightness_is_clamped_and_keeps_alpha() {
+        assert_eq!(
+            rgb(250, 128, 114).lighten_lab(percent(100)),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(
+            rgb(128, 128, 128).lighten_lab(percent(100)),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(rgb(250, 128, 114).darken_lab(percent(100)), rgb(0, 0, 0));
+        assert_eq!(
+            rgb(250, 128, 114).lighten_lab(percent(0)),
+            rgb(250, 128, 114)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 0.5).darken_lab(percent(10)).alpha_f32(),
+            128.0 / 255.0
+        );
+        assert_eq!(
+            hsla(6, 93, 71, 0.5).lighten_lab(percent(10)),
+            hsla(6, 93, 71, 0.5)
+                .to_rgba()
+                .lighten_lab(percent(10))
+                .to_hsla()
+        );
+    }
+
+    #[test]
+    fn brightening_scales_linear_light() {
+        let grey = rgb(128, 128, 128);
+
+        // Doubling the light of a mid grey is far gentler than doubling its HSL lightness,
+        // which already gives white.
+        assert_eq!(grey.brighten(percent(100)), rgb(176, 176, 176));
+        assert_eq!(grey.lighten(percent(50)), rgb(255, 255, 255));
+        assert_eq!(grey.brighten(percent(100)).dim(percent(50)), grey);
+
+        // Each channel is scaled by the same factor, so hues survive.
+        let brown = rgb(100, 50, 0).brighten(percent(50));
+        assert_approximately_eq!(
+            brown.to_hsl().h.to_angle(),
+            rgb(100, 50, 0).to_hsl().h.to_angle()
+        );
+
+        assert_eq!(rgb(250, 128, 114).brighten(percent(100)).r, percent(100));
+        assert_eq!(rgb(250, 128, 114).dim(percent(100)), rgb(0, 0, 0));
+        assert_eq!(rgb(250, 128, 114).brighten(percent(0)), rgb(250, 128, 114));
+        assert_eq!(
+            hsla(6, 93, 71, 0.5).brighten(percent(30)),
+            hsla(6, 93, 71, 0.5)
+                .to_rgba()
+                .brighten(percent(30))
+                .to_hsla()
+        );
+        assert_eq!(hsla(6, 93, 71, 0.5).dim(percent(30)).a, percent(50));
+    }
+
+    #[test]
+    fn warming_increases_red_bias() {
+        for color in [rgb(128, 128, 128), rgb(23, 98, 119), rgb(148, 189, 209)] {
+            let red_blue = |c: RGB| c.r.as_u8() as i32 - c.b.as_u8() as i32;
+            let red_green = |c: RGB| c.r.as_u8() as i32 - c.g.as_u8() as i32;
+
+            assert!(red_blue(color.warm(percent(30))) > red_blue(color));
+            assert!(red_blue(color.warm(percent(60))) > red_blue(color.warm(percent(30))));
+            assert!(red_green(color.warm(percent(30))) > red_green(color));
+            assert!(red_blue(color.cool(percent(30))) < red_blue(color));
+        }
+    }
+
+    #[test]
+    fn temperature_shifts_preserve_lightness_and_alpha() {
+        let salmon = rgba(250, 128, 114, 0.5);
+
+        let lightness = |c: RGBA| Oklab::from_rgb(c.to_rgb()).l;
+
+        assert!((lightness(salmon.warm(percent(50))) - lightness(salmon)).abs() < 0.01);
+        assert!((lightness(salmon.cool(percent(50))) - lightness(salmon)).abs() < 0.01);
+        assert_eq!(salmon.warm(percent(50)).alpha_f32(), salmon.alpha_f32());
+        assert_eq!(
+            hsla(6, 93, 71, 0.5).cool(percent(40)),
+            hsla(6, 93, 71, 0.5).to_rgba().cool(percent(40)).to_hsla()
+        );
+    }
+
+    #[test]
+    fn can_greyscale() {
+        assert_approximately_eq!(rgb(128, 242, 13).greyscale(), rgb(128, 128, 128));
+        assert_approximately_eq!(
+            rgba(128, 242, 13, 1.0).greyscale(),
+            rgba(128, 128, 128, 1.0)
+        );
+        assert_approximately_eq!(hsl(90, 90, 50).greyscale(), hsl(90, 0, 50));
+        assert_approximately_eq!(hsla(90, 90, 50, 1.0).greyscale(), hsla(90, 0, 50, 1.0));
+    }
+
+    #[test]
+    fn can_map_rgb_channels() {
+        // Posterizes every channel to 4 levels.
+        let posterize = |c: Ratio| Ratio::from_f32((c.as_f32() * 3.0).round() / 3.0);
+
+        assert_eq!(rgb(240, 100, 30).map_rgb(posterize), rgb(255, 85, 0));
+        assert_eq!(
+            rgba(240, 100, 30, 0.3).map_rgb(posterize),
+            rgba(255, 85, 0, 0.3)
+        );
+        assert_approximately_eq!(
+            rgb(240, 100, 30).to_hsl().map_rgb(posterize),
+            rgb(255, 85, 0).to_hsl()
+        );
+        assert_approximately_eq!(
+            rgba(240, 100, 30, 0.3).to_hsla().map_rgb(posterize),
+            rgba(255, 85, 0, 0.3).to_hsla()
+        );
+        assert_eq!(
+            hsl(6, 93, 71).map_rgb(|c| c),
+            hsl(6, 93, 71).to_rgb().to_hsl()
+        );
+    }
+
+    #[test]
+    fn can_posterize() {
+        assert_eq!(rgb(240, 100, 30).posterize(4), rgb(255, 85, 0));
+        assert_eq!(rgb(240, 100, 30).posterize(3), rgb(255, 128, 0));
+        assert_eq!(rgb(240, 100, 30).posterize(1), rgb(255, 0, 0));
+        assert_eq!(rgb(240, 100, 30).posterize(0), rgb(255, 0, 0));
+        assert_eq!(rgba(128, 127, 0, 0.3).posterize(2), rgba(255, 0, 0, 0.3));
+        assert_approximately_eq!(
+            hsla(18, 88, 53, 0.3).posterize(4),
+            rgba(255, 85, 0, 0.3).to_hsla()
+        );
+
+        for value in 0..=255 {
+            let color = rgb(value, 255 - value, value / 2);
+
+            assert_eq!(color.posterize(256), color);
+            assert_eq!(color.posterize(u16::MAX), color);
+        }
+    }
+
+    #[test]
+    fn can_detect_grayscale() {
+        assert!(rgb(128, 128, 128).is_grayscale());
+        assert!(!rgb(128, 128, 129).is_grayscale());
+        assert!(rgba(128, 128, 128, 0.5).is_grayscale());
+        assert!(!rgba(128, 129, 128, 1.0).is_grayscale());
+
+        assert!(hsl(90, 0, 50).is_grayscale());
+        assert!(hsla(90, 0, 50, 0.5).is_grayscale());
+        assert!(hsl(90, 50, 0).is_grayscale());
+        assert!(!hsl(90, 90, 50).is_grayscale());
+
+        // A near grey keeps its little saturation when converted to HSL and back.
+        assert!(rgb(128, 128, 128).to_hsl().is_grayscale());
+        assert_eq!(rgb(128, 128, 129).to_hsl().s.to_ratio(), Ratio::from_u8(1));
+        assert!(!rgb(128, 128, 129).to_hsl().is_grayscale());
+        assert!(!rgb(128, 128, 140).to_hsl().is_grayscale());
+        assert!(rgb(128, 242, 13).greyscale().is_grayscale());
+    }
+
+    #[test]
+    fn keeps_the_hue_of_near_greys() {
+        assert_eq!(rgb(128, 128, 128).to_hsl().to_string(), "hsl(0, 0%, 50%)");
+        assert_eq!(rgb(128, 128, 129).to_hsl().h, deg(240).into());
+        assert_eq!(rgb(128, 129, 128).to_hsl().h, deg(120).into());
+        assert_eq!(rgb(129, 128, 128).to_hsla().h, deg(0).into());
+        assert_eq!(rgb(128, 129, 129).to_hsl().h, deg(180).into());
+        assert_eq!(rgba(128, 128, 129, 0.5).to_hsla().h, deg(240).into());
+        assert_eq!(rgb(128, 128, 129).to_hsl().to_string(), "hsl(240, 0%, 50%)");
+
+        // The hue is kept, so saturating a near grey tints it rather than turning it red.
+        assert_eq!(
+            rgb(128, 128, 129).saturate(percent(50)).to_hsl().h,
+            deg(240).into()
+        );
+    }
+
+    #[test]
+    fn can_invert_lightness() {
+        let light_blue = rgb(173, 216, 230);
+        let dark_blue = light_blue.invert_lightness();
+
+        assert_eq!(dark_blue, rgb(25, 68, 82));
+        assert_approximately_eq!(
+            dark_blue.to_hsl().h.to_angle(),
+            light_blue.to_hsl().h.to_angle()
+        );
+        assert_approximately_eq!(
+            dark_blue.to_hsl().s.to_ratio(),
+            light_blue.to_hsl().s.to_ratio()
+        );
+        assert!(dark_blue.luminance() < light_blue.luminance());
+
+        assert_eq!(hsl(195, 53, 79).invert_lightness(), hsl(195, 53, 21));
+        assert_eq!(
+            hsl(195, 53, 50).invert_lightness().to_string(),
+            "hsl(195, 53%, 50%)"
+        );
+        assert_eq!(
+            hsla(195, 53, 79, 0.3).invert_lightness(),
+            hsla(195, 53, 21, 0.3)
+        );
+        assert_eq!(
+            rgba(173, 216, 230, 0.3).invert_lightness(),
+            rgba(25, 68, 82, 0.3)
+        );
+        assert_eq!(rgb(0, 0, 0).invert_lightness(), rgb(255, 255, 255));
+
+        for lightness in 0..=100 {
+            let color = hsla(300, 40, lightness, 0.5);
+
+            assert_eq!(color.invert_lightness().invert_lightness(), color);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid value for percentage")]
+    fn rejects_hsl_percentages_over_full_in_debug_builds() {
+        hsl(6, 200, 50);
+    }
+
+    #[test]
+    fn can_find_the_dominant_channel() {
+        assert_eq!(rgb(200, 10, 10).dominant_channel(), Some(Channel::Red));
+        assert_eq!(rgb(10, 200, 10).dominant_channel(), Some(Channel::Green));
+        assert_eq!(rgb(10, 10, 200).dominant_channel(), Some(Channel::Blue));
+        assert_eq!(rgb(128, 128, 129).dominant_channel(), Some(Channel::Blue));
+
+        assert_eq!(rgb(200, 200, 10).dominant_channel(), Some(Channel::Red));
+        assert_eq!(rgb(200, 10, 200).dominant_channel(), Some(Channel::Red));
+        assert_eq!(rgb(10, 200, 200).dominant_channel(), Some(Channel::Green));
+
+        assert_eq!(rgb(0, 0, 0).dominant_channel(), None);
+        assert_eq!(rgb(255, 255, 255).dominant_channel(), None);
+        assert_eq!(hsl(120, 0, 40).dominant_channel(), None);
+
+        assert_eq!(
+            rgba(10, 200, 10, 0.0).dominant_channel(),
+            Some(Channel::Green)
+        );
+        assert_eq!(hsl(120, 100, 50).dominant_channel(), Some(Channel::Green));
+        assert_eq!(
+            hsla(240, 100, 50, 0.5).dominant_channel(),
+            Some(Channel::Blue)
+        );
+    }
+
+    #[test]
+    fn can_compute_contrast_ratio() {
+        let white = rgb(255, 255, 255);
+
+        assert_eq!(white.contrast_ratio(white), 1.0);
+        assert!((rgb(0, 0, 0).contrast_ratio(white) - 21.0).abs() < 0.001);
+        assert_eq!(
+            rgb(250, 128, 114).contrast_ratio(white),
+            white.contrast_ratio(rgba(250, 128, 114, 0.5))
+        );
+        assert!((rgb(119, 119, 119).contrast_ratio(white) - 4.48).abs() < 0.01);
+        assert!((rgb(0, 0, 255).luminance() - 0.0722).abs() < 0.0001);
+    }
+
+    #[test]
+    fn can_report_text_contrast() {
+        let (on_black, on_white) = rgb(0, 0, 0).text_contrast_report();
+        assert_eq!(on_black, 1.0);
+        assert!((on_white - 21.0).abs() < 0.001);
+
+        let salmon = rgb(250, 128, 114);
+        assert_eq!(
+            salmon.text_contrast_report(),
+            (
+                salmon.contrast_ratio(rgb(0, 0, 0)),
+                salmon.contrast_ratio(rgb(255, 255, 255))
+            )
+        );
+        assert_eq!(
+            hsla(6, 93, 71, 0.2).text_contrast_report(),
+            hsl(6, 93, 71).text_contrast_report()
+        );
+    }
+
+    #[test]
+    fn ensures_contrast_whenever_achievable() {
+        let backgrounds = [
+            rgb(255, 255, 255),
+            rgb(0, 0, 0),
+            rgb(128, 128, 128),
+            rgb(250, 128, 114),
+            rgb(23, 98, 119),
+        ];
+
+        for background in backgrounds {
+            for h in (0..360).step_by(45) {
+                for s in [0, 50, 100] {
+                    for l in (0..=100).step_by(10) {
+                        for min_ratio in [3.0, 4.5, 7.0] {
+                            let color = hsla(h, s, l, 0.5);
+                            let adjusted = color.ensure_contrast(background, min_ratio);
+                            let achievable = [0, 100]
+                                .iter()
+                                .any(|&l| hsl(h, s, l).contrast_ratio(background) >= min_ratio);
+
+                            assert_eq!(adjusted.h, color.h);
+                            assert_eq!(adjusted.s, color.s);
+                            assert_eq!(adjusted.a, color.a);
+                            assert_eq!(
+                                adjusted.contrast_ratio(background) >= min_ratio,
+                                achievable,
+                                "{} on {}",
+                                color,
+                                background
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ensure_contrast_keeps_colors_with_enough_contrast() {
+        let white = rgb(255, 255, 255);
+        let navy = rgba(0, 0, 80, 0.5);
+
+        assert_eq!(navy.ensure_contrast(white, 4.5), navy);
+        assert_eq!(navy.to_rgb().ensure_contrast(white, 4.5), navy.to_rgb());
+        assert_eq!(hsl(6, 93, 20).ensure_contrast(white, 4.5), hsl(6, 93, 20));
+
+        // Unreachable ratios settle on whichever extreme has the most contrast.
+        assert_eq!(
+            rgb(128, 128, 128).ensure_contrast(white, 22.0),
+            rgb(0, 0, 0)
+        );
+        assert_eq!(
+            rgb(128, 128, 128).ensure_contrast(rgb(0, 0, 0), 22.0),
+            rgb(255, 255, 255)
+        );
+    }
+
+    #[test]
+    fn can_measure_hue_distance() {
+        let red = rgb(255, 0, 0);
+
+        assert_eq!(red.hue_distance(red), deg(0));
+        assert_eq!(red.hue_distance(rgb(255, 128, 0)), deg(30));
+        assert_eq!(rgb(255, 128, 0).hue_distance(red), deg(30));
+        assert_eq!(red.hue_distance(rgb(0, 255, 255)), deg(180));
+        assert_eq!(red.hue_distance(hsla(240, 100, 50, 0.5)), deg(120));
+        assert_eq!(hsl(10, 80, 50).hue_distance(hsl(350, 80, 50)), deg(20));
+        assert_eq!(hsl(179, 80, 50).hue_distance(hsl(0, 80, 50)), deg(179));
+        assert_eq!(hsl(181, 80, 50).hue_distance(hsl(0, 80, 50)), deg(179));
+
+        assert_eq!(red.hue_distance(rgb(255, 255, 255)), deg(180));
+        assert_eq!(hsl(10, 0, 50).hue_distance(hsl(10, 80, 50)), deg(180));
+        assert_eq!(hsl(10, 80, 50).hue_distance(hsl(10, 0, 50)), deg(180));
+    }
+
+    #[test]
+    fn can_measure_distance() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+
+        assert_eq!(black.distance(black), 0.0);
+        assert_eq!(hsl(6, 93, 71).distance(hsla(6, 93, 71, 0.5)), 0.0);
+        assert!((black.distance(white) - 100.0).abs() < 0.01);
+        assert!((white.distance(black) - 100.0).abs() < 0.01);
+
+        assert!(rgb(128, 128, 128).distance(rgb(128, 128, 129)) < 1.0);
+        assert!(rgba(255, 0, 0, 1.0).distance(hsl(0, 100, 50)) < 1.0);
+        assert!(
+            rgb(255, 0, 0).distance(rgb(255, 128, 0)) < rgb(255, 0, 0).distance(rgb(0, 0, 255))
+        );
+    }
+
+    #[test]
+    fn can_find_nearest_named_color() {
+        assert_eq!(rgb(100, 149, 237).nearest_named(), "cornflowerblue");
+        assert_eq!(rgba(100, 149, 237, 0.5).nearest_named(), "cornflowerblue");
+        assert_eq!(rgb(101, 150, 235).nearest_named(), "cornflowerblue");
+        assert_eq!(rgb(0, 0, 0).nearest_named(), "black");
+        assert_eq!(rgb(254, 254, 254).nearest_named(), "white");
+        assert_eq!(rgb(128, 128, 128).nearest_named(), "gray");
+        assert_eq!(hsl(0, 100, 50).nearest_named(), "red");
+        assert_eq!(hsla(120, 100, 25, 1.0).nearest_named(), "green");
+    }
+
+    #[test]
+    fn can_premultiply_alpha() {
+        assert_eq!(
+            rgba(250, 128, 114, 0.5).premultiplied(),
+            rgba(125, 64, 57, 0.5)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 1.0).premultiplied(),
+            rgba(250, 128, 114, 1.0)
+        );
+        assert_eq!(rgba(250, 128, 114, 0.0).premultiplied(), rgba(0, 0, 0, 0.0));
+    }
+
+    #[test]
+    fn can_unpremultiply_alpha() {
+        assert_eq!(
+            rgba(64, 32, 16, 0.5).unpremultiplied(),
+            rgba(128, 64, 32, 0.5)
+        );
+        assert_approximately_eq!(
+            rgba(125, 64, 57, 0.5).unpremultiplied(),
+            rgba(250, 128, 114, 0.5)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 1.0).unpremultiplied(),
+            rgba(250, 128, 114, 1.0)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 0.0).unpremultiplied(),
+            rgba(0, 0, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn can_round_trip_premultiplied_alpha() {
+        let salmon = rgba(250, 128, 114, 0.5);
+
+        assert_approximately_eq!(salmon.premultiplied().unpremultiplied(), salmon);
+        assert_eq!(
+            salmon.with_red(0).premultiplied().unpremultiplied(),
+            salmon.with_red(0)
+        );
+    }
+
+    #[test]
+    fn can_generate_hue_wheel() {
+        let wheel = HSL::hue_wheel(6, percent(70), percent(50));
+        let hues: Vec<u16> = wheel.iter().map(|color| color.hue()).collect();
+
+        assert_eq!(hues, [0, 60, 120, 180, 240, 300]);
+        assert!(wheel
+            .iter()
+            .all(|color| color.saturation_pct() == 70 && color.lightness_pct() == 50));
+
+        let hues: Vec<u16> = HSL::hue_wheel(7, percent(70), percent(50))
+            .iter()
+            .map(|color| color.hue())
+            .collect();
+
+        assert_eq!(hues, [0, 51, 103, 154, 206, 257, 309]);
+    }
+
+    #[test]
+    fn can_generate_small_hue_wheels() {
+        assert!(HSL::hue_wheel(0, percent(70), percent(50)).is_empty());
+        assert_eq!(
+            HSL::hue_wheel(1, percent(60), percent(40)),
+            [hsl(0, 60, 40)]
+        );
+    }
+
+    #[test]
+    fn can_try_new_hsl() {
+        assert_eq!(HSL::try_new(6, 93, 71), Ok(hsl(6, 93, 71)));
+        assert_eq!(HSL::try_new(0, 0, 0), Ok(hsl(0, 0, 0)));
+        assert_eq!(HSL::try_new(360, 100, 100), Ok(hsl(0, 100, 100)));
+        assert_eq!(HSL::try_new(-30, 50, 50), Ok(hsl(330, 50, 50)));
+    }
+
+    #[test]
+    fn handles_out_of_range_try_new_hsl() {
+        let saturation = HSL::try_new(400, 150, 200).unwrap_err();
+        let lightness = HSL::try_new(6, 93, -1).unwrap_err();
+
+        assert_eq!(saturation.channel(), "saturation");
+        assert_eq!(saturation.value(), 150);
+        assert_eq!(lightness.channel(), "lightness");
+        assert_eq!(lightness.value(), -1);
+        assert_eq!(
+            lightness.to_string(),
+            "lightness of -1 is outside of the 0-100% range"
+        );
+        assert!(HSL::try_new(6, 101, 71).is_err());
+        assert!(HSL::try_new(6, 93, 256).is_err());
+    }
+
+    #[test]
+    fn can_access_channels() {
+        let rgb = rgb(250, 128, 114);
+        let rgba = rgba(250, 128, 114, 0.5);
+        let hsl = hsl(6, 93, 71);
+        let hsla = hsla(6, 93, 71, 0.0);
+
+        assert_eq!((rgb.red(), rgb.green(), rgb.blue()), (250, 128, 114));
+        assert_eq!((rgba.red(), rgba.green(), rgba.blue()), (250, 128, 114));
+        assert_eq!(rgba.alpha_f32(), rgba.a.as_f32());
+        assert_eq!(
+            (hsl.hue(), hsl.saturation_pct(), hsl.lightness_pct()),
+            (6, 93, 71)
+        );
+        assert_eq!(
+            (hsla.hue(), hsla.saturation_pct(), hsla.lightness_pct()),
+            (6, 93, 71)
+        );
+        assert_eq!(hsla.alpha_f32(), 0.0);
+    }
+
+    #[test]
+    fn can_replace_channels() {
+        assert_eq!(rgb(1, 2, 3).with_red(250), rgb(250, 2, 3));
+        assert_eq!(rgb(1, 2, 3).with_green(128), rgb(1, 128, 3));
+        assert_eq!(rgb(1, 2, 3).with_blue(114), rgb(1, 2, 114));
+        assert_eq!(
+            rgba(1, 2, 3, 0.5)
+                .with_red(250)
+                .with_green(128)
+                .with_blue(114),
+            rgba(250, 128, 114, 0.5)
+        );
+    }
+
+    #[test]
+    fn can_clone() {
+        let rgb_color = rgb(5, 10, 15);
+        let rgba_color = rgba(5, 10, 15, 1.0);
+        let hsl_color = hsl(6, 93, 71);
+        let hsla_color = hsla(6, 93, 71, 1.0);
+
+        assert_eq!(rgb_color, rgb_color.clone());
+        assert_eq!(rgba_color, rgba_color.clone());
+        assert_eq!(hsl_color, hsl_color.clone());
+        assert_eq!(hsla_color, hsla_color.clone());
+    }
+
+    #[test]
+    fn can_copy() {
+        let rgb_color = rgb(172, 95, 82);
+        let rgba_color = rgba(172, 95, 82, 1.0);
+        let hsl_color = hsl(9, 35, 50);
+        let hsla_color = hsla(9, 35, 50, 1.0);
+
+        let copied_rgb_color = rgb_color;
+        let copied_rgba_color = rgba_color;
+        let copied_hsl_color = hsl_color;
+        let copied_hsla_color = hsla_color;
+
+        assert_eq!(rgb_color, copied_rgb_color);
+        assert_eq!(rgba_color, copied_rgba_color);
+        assert_eq!(hsl_color, copied_hsl_color);
+        assert_eq!(hsla_color, copied_hsla_color);
+    }
+
+    #[test]
+    fn can_debug() {
+        let rgb_value = format!("{:?}", rgb(5, 10, 15));
+        let rgba_value = format!("{:?}", rgba(5, 10, 15, 1.0));
+        let hsl_value = format!("{:?}", hsl(6, 93, 71));
+        let hsla_value = format!("{:?}", hsla(6, 93, 71, 1.0));
+
+        assert_eq!(rgb_value, "RGB { r: Ratio(5), g: Ratio(10), b: Ratio(15) }");
+        assert_eq!(
+            rgba_value,
+            "RGBA { r: Ratio(5), g: Ratio(10), b: Ratio(15), a: Ratio(255) }"
+        );
+        assert_eq!(
+            hsl_value,
+            "HSL { h: FineAngle(600), s: FineRatio(60948), l: FineRatio(46530) }"
+        );
+        assert_eq!(
             hsla_value,
-            "HSLA { h: Angle { degrees: 6 }, s: Ratio(237), l: Ratio(181), a: Ratio(255) }"
+            "HSLA { h: FineAngle(600), s: FineRatio(60948), l: FineRatio(46530), a: Ratio(255) }"
         );
     }
 
@@ -881,6 +3415,19 @@ mod tests {
         assert_eq!(hsla.to_css(), "hsla(6, 93%, 71%, 1.00)");
     }
 
+    #[test]
+    fn can_convert_to_modern_css() {
+        let rgb = rgb(5, 10, 255);
+        let rgba = rgba(5, 10, 255, 0.5);
+        let hsl = hsl(6, 93, 71);
+        let hsla = hsla(6, 93, 71, 0.5);
+
+        assert_eq!(rgb.to_css_modern(), "rgb(5 10 255)");
+        assert_eq!(rgba.to_css_modern(), "rgb(5 10 255 / 50%)");
+        assert_eq!(hsl.to_css_modern(), "hsl(6 93% 71%)");
+        assert_eq!(hsla.to_css_modern(), "hsl(6 93% 71% / 50%)");
+    }
+
     #[test]
     fn can_convert_to_hex() {
         let rgb = rgb(5, 10, 255);
@@ -894,6 +3441,458 @@ mod tests {
         assert_eq!(hsla.to_hex(), "#fa7e70ff");
     }
 
+    #[test]
+    fn can_convert_to_modern_css_with_deg() {
+        assert_eq!(hsl(6, 93, 71).to_css_modern_deg(), "hsl(6deg 93% 71%)");
+        assert_eq!(hsl(0, 0, 0).to_css_modern_deg(), "hsl(0deg 0% 0%)");
+        assert_eq!(
+            hsla(359, 93, 71, 0.5).to_css_modern_deg(),
+            "hsl(359deg 93% 71% / 50%)"
+        );
+        assert_eq!(hsl(6, 93, 71).to_css(), "hsl(6, 93%, 71%)");
+    }
+
+    #[test]
+    fn rounds_hsl_css_percentages_half_up() {
+        let color = HSL {
+            h: deg(6).into(),
+            s: Ratio::from_u8(14).into(),
+            l: Ratio::from_u8(37).into(),
+        };
+
+        assert_eq!(color.to_css(), "hsl(6, 5%, 15%)");
+        assert_eq!(color.to_css_modern(), "hsl(6 5% 15%)");
+        assert_eq!(hsl(6, 93, 71).to_css(), "hsl(6, 93%, 71%)");
+
+        for percentage in 0..=100 {
+            assert_eq!(
+                hsl(6, percentage, percentage).to_css(),
+                format!("hsl(6, {0}%, {0}%)", percentage)
+            );
+        }
+    }
+
+    #[test]
+    fn can_convert_to_minimal_css() {
+        assert_eq!(rgb(250, 128, 114).to_css_minimal(), "rgb(250, 128, 114)");
+        assert_eq!(
+            rgba(250, 128, 114, 1.0).to_css_minimal(),
+            "rgb(250, 128, 114)"
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 0.99).to_css_minimal(),
+            "rgba(250, 128, 114, 0.99)"
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 0.0).to_css_minimal(),
+            "rgba(250, 128, 114, 0.00)"
+        );
+        assert_eq!(hsl(6, 93, 71).to_css_minimal(), "hsl(6, 93%, 71%)");
+        assert_eq!(hsla(6, 93, 71, 1.0).to_css_minimal(), "hsl(6, 93%, 71%)");
+        assert_eq!(
+            hsla(6, 93, 71, 0.5).to_css_minimal(),
+            "hsla(6, 93%, 71%, 0.50)"
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 1.0).to_css(),
+            "rgba(250, 128, 114, 1.00)"
+        );
+    }
+
+    #[test]
+    fn can_convert_transparent_to_minimal_css() {
+        assert_eq!(rgba(0, 0, 0, 0.0).to_css_minimal(), "transparent");
+        assert_eq!(hsla(0, 0, 0, 0.0).to_css_minimal(), "transparent");
+        assert_eq!(hsla(120, 50, 0, 0.0).to_css_minimal(), "transparent");
+        assert_eq!(rgba(0, 0, 1, 0.0).to_css_minimal(), "rgba(0, 0, 1, 0.00)");
+        assert_eq!(rgb(0, 0, 0).to_css_minimal(), "rgb(0, 0, 0)");
+        assert_eq!(rgba(0, 0, 0, 0.0).to_css(), "rgba(0, 0, 0, 0.00)");
+    }
+
+    #[test]
+    fn can_convert_to_bare_hex() {
+        let rgb = rgb(5, 10, 255);
+        let rgba = rgba(5, 10, 255, 1.0);
+        let hsl = hsl(6, 93, 71);
+        let hsla = hsla(6, 93, 71, 1.0);
+
+        assert_eq!(rgb.to_hex_bare(), "050aff");
+        assert_eq!(rgba.to_hex_bare(), "050affff");
+        assert_eq!(hsl.to_hex_bare(), "fa7e70");
+        assert_eq!(hsla.to_hex_bare(), "fa7e70ff");
+    }
+
+    #[test]
+    fn can_convert_to_css_vars() {
+        assert_eq!(
+            rgb(5, 10, 255).to_css_var("link"),
+            "--link: rgb(5, 10, 255);"
+        );
+        assert_eq!(
+            rgba(5, 10, 255, 0.5).to_css_var("--link"),
+            "--link: rgba(5, 10, 255, 0.50);"
+        );
+        assert_eq!(
+            hsl(6, 93, 71).to_css_var("accent"),
+            "--accent: hsl(6, 93%, 71%);"
+        );
+
+        let theme = [("fg", hsla(0, 0, 0, 1.0)), ("--bg", hsla(0, 0, 100, 0.5))];
+
+        assert_eq!(
+            to_css_vars(&theme),
+            ":root {\n  --fg: hsla(0, 0%, 0%, 1.00);\n  --bg: hsla(0, 0%, 100%, 0.50);\n}\n"
+        );
+        assert_eq!(to_css_vars::<RGB>(&[]), ":root {\n}\n");
+    }
+
+    #[test]
+    fn can_convert_to_short_hex() {
+        assert_eq!(rgb(255, 170, 0).to_hex_short().as_deref(), Some("#fa0"));
+        assert_eq!(rgb(255, 171, 0).to_hex_short(), None);
+        assert_eq!(
+            rgba(255, 170, 0, 1.0).to_hex_short().as_deref(),
+            Some("#fa0f")
+        );
+        assert_eq!(rgba(255, 170, 0, 0.5).to_hex_short(), None);
+        assert_eq!(hsl(0, 0, 100).to_hex_short().as_deref(), Some("#fff"));
+        assert_eq!(hsla(0, 0, 0, 0.0).to_hex_short().as_deref(), Some("#0000"));
+        assert_eq!(hsl(6, 93, 71).to_hex_short(), None);
+
+        for hex in ["#fa0", "#fa0f", "#0000"] {
+            assert_eq!(
+                hex.parse::<RGBA>().unwrap().to_hex_short().unwrap().len(),
+                5
+            );
+        }
+    }
+
+    #[test]
+    fn can_convert_to_u32() {
+        let rgb = rgb(5, 10, 255);
+        let rgba = rgba(5, 10, 255, 0.0);
+        let hsl = hsl(6, 93, 71);
+        let hsla = hsla(6, 93, 71, 1.0);
+
+        assert_eq!(rgb.to_u32(), 0x00050aff);
+        assert_eq!(rgba.to_u32(), 0x050aff00);
+        assert_eq!(hsl.to_u32(), 0x00fa7e70);
+        assert_eq!(hsla.to_u32(), 0xfa7e70ff);
+    }
+
+    #[test]
+    fn can_convert_from_u32() {
+        assert_eq!(RGB::from_u32(0x00050aff), rgb(5, 10, 255));
+        assert_eq!(RGB::from_u32(0xff050aff), rgb(5, 10, 255));
+        assert_eq!(RGBA::from_u32(0x050aff00), rgba(5, 10, 255, 0.0));
+        assert_eq!(RGBA::from_u32(0x050affff), rgba(5, 10, 255, 1.0));
+    }
+
+    #[test]
+    fn can_round_trip_u32() {
+        let rgb = rgb(250, 128, 114);
+        let rgba = rgba(250, 128, 114, 0.5);
+
+        assert_eq!(RGB::from_u32(rgb.to_u32()), rgb);
+        assert_eq!(RGBA::from_u32(rgba.to_u32()), rgba);
+        assert_eq!(RGB::from_u32(0xfa8072).to_u32(), 0xfa8072);
+        assert_eq!(RGBA::from_u32(0xfa807280).to_u32(), 0xfa807280);
+    }
+
+    #[test]
+    fn can_convert_from_arrays_and_tuples() {
+        assert_eq!(RGB::from([5, 10, 255]), rgb(5, 10, 255));
+        assert_eq!(RGB::from((5, 10, 255)), rgb(5, 10, 255));
+        assert_eq!(RGBA::from([5, 10, 255, 0]), rgba(5, 10, 255, 0.0));
+        assert_eq!(RGBA::from((5, 10, 255, 255)), rgba(5, 10, 255, 1.0));
+        assert_eq!(
+            RGBA::from([5, 10, 255, 128]).to_css(),
+            "rgba(5, 10, 255, 0.50)"
+        );
+    }
+
+    #[test]
+    fn can_round_trip_arrays() {
+        let rgb = rgb(250, 128, 114);
+        let rgba = rgba(250, 128, 114, 0.5);
+
+        assert_eq!(RGB::from(rgb.to_array()), rgb);
+        assert_eq!(RGBA::from(rgba.to_array()), rgba);
+        assert_eq!(RGB::from([250, 128, 114]).to_array(), [250, 128, 114]);
+        assert_eq!(
+            RGBA::from([250, 128, 114, 77]).to_array(),
+            [250, 128, 114, 77]
+        );
+    }
+
+    #[test]
+    fn has_black_defaults() {
+        assert_eq!(RGB::default(), rgb(0, 0, 0));
+        assert_eq!(RGBA::default(), rgba(0, 0, 0, 0.0));
+        assert_eq!(HSL::default(), hsl(0, 0, 0));
+        assert_eq!(HSLA::default(), hsla(0, 0, 0, 0.0));
+        assert_eq!(crate::HSV::default(), crate::hsv(0, 0, 0));
+        assert_eq!(Ratio::default(), percent(0));
+        assert_eq!(Angle::default(), deg(0));
+
+        #[derive(Default)]
+        struct Theme {
+            background: RGBA,
+            foreground: HSL,
+        }
+
+        let theme = Theme::default();
+        assert_eq!(theme.background.to_css_minimal(), "transparent");
+        assert_eq!(theme.foreground.to_css(), "hsl(0, 0%, 0%)");
+    }
+
+    #[test]
+    fn builds_colors_in_const_contexts() {
+        const PRIMARY: HSL = hsl(200, 40, 70);
+        const ACCENT: HSL = HSL {
+            h: FineAngle::from_angle(deg(-160)),
+            s: FineRatio::from_percentage(40),
+            l: FineRatio::from_percentage(70),
+        };
+
+        assert_eq!(PRIMARY, ACCENT);
+        assert_eq!(PRIMARY.to_css(), "hsl(200, 40%, 70%)");
+    }
+
+    #[test]
+    fn can_convert_to_f32_arrays() {
+        assert_eq!(rgb(0, 0, 0).to_f32_array(), [0.0, 0.0, 0.0]);
+        assert_eq!(rgb(255, 255, 255).to_f32_array(), [1.0, 1.0, 1.0]);
+        assert_eq!(rgba(0, 255, 0, 0.0).to_f32_array(), [0.0, 1.0, 0.0, 0.0]);
+
+        let [r, g, b, a] = rgba(250, 128, 114, 0.5).to_f32_array();
+        assert_eq!([r, g, b], rgb(250, 128, 114).to_f32_array());
+        assert_eq!(a, 128.0 / 255.0);
+    }
+
+    #[test]
+    fn can_write_into_a_buffer() {
+        let mut buffer = String::new();
+
+        rgb(250, 128, 114).write_css(&mut buffer).unwrap();
+        buffer.push(' ');
+        rgba(250, 128, 114, 0.5).write_hex(&mut buffer).unwrap();
+        buffer.push(' ');
+        hsl(6, 93, 71).write_css(&mut buffer).unwrap();
+        buffer.push(' ');
+        hsla(6, 93, 71, 0.5).write_hex(&mut buffer).unwrap();
+
+        assert_eq!(
+            buffer,
+            "rgb(250, 128, 114) #fa807280 hsl(6, 93%, 71%) #fa7e7080"
+        );
+        assert_eq!(hsla(6, 93, 71, 0.5).to_css(), "hsla(6, 93%, 71%, 0.50)");
+        assert_eq!(hsl(6, 93, 71).to_hex(), hsl(6, 93, 71).to_rgb().to_hex());
+    }
+
+    #[test]
+    fn can_join_palettes() {
+        let palette = [hsl(6, 93, 71), hsl(200, 40, 70), hsl(0, 0, 100)];
+
+        assert_eq!(
+            palette_to_css(&palette),
+            "hsl(6, 93%, 71%), hsl(200, 40%, 70%), hsl(0, 0%, 100%)"
+        );
+        assert_eq!(palette_to_hex(&palette), "#fa7e70, #94bdd1, #ffffff");
+        assert_eq!(palette_to_css(&[rgba(1, 2, 3, 0.5)]), "rgba(1, 2, 3, 0.50)");
+        assert_eq!(palette_to_hex(&[rgb(1, 2, 3)]), "#010203");
+        assert_eq!(palette_to_css::<RGB>(&[]), "");
+        assert_eq!(palette_to_hex::<RGBA>(&[]), "");
+    }
+
+    #[test]
+    fn can_match_hex_strings() {
+        let salmon = rgb(250, 128, 114);
+
+        assert!(salmon.matches_hex("#fa8072"));
+        assert!(salmon.matches_hex("fa8072"));
+        assert!(salmon.matches_hex("#FA8072"));
+        assert!(salmon.matches_hex("#f97f73"));
+        assert!(!salmon.matches_hex("#f88072"));
+        assert!(!salmon.matches_hex("#fa8070"));
+        assert!(rgb(255, 0, 0).matches_hex("#f00"));
+        assert!(rgb(255, 1, 0).matches_hex("#f00f"));
+
+        assert!(salmon.to_hsl().matches_hex("#fa8072"));
+        assert!(salmon.to_hsla().matches_hex(&salmon.to_hex()));
+        assert!(hsl(200, 40, 70).matches_hex(&hsl(200, 40, 70).to_hex()));
+
+        assert!(rgba(250, 128, 114, 0.5).matches_hex("#fa8072"));
+        assert!(rgba(250, 128, 114, 0.5).matches_hex("#fa807280"));
+        assert!(!rgba(250, 128, 114, 0.5).matches_hex("#fa8072ff"));
+        assert!(!salmon.matches_hex("#fa807280"));
+        assert!(hsla(6, 93, 71, 0.5).matches_hex("#fa7e7080"));
+
+        for invalid in [
+            "", "#", "#fa807", "#fa8072f", "##fa8072", "#gg8072", "salmon",
+        ] {
+            assert!(!salmon.matches_hex(invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn can_convert_to_css_percentages() {
+        assert_eq!(rgb(255, 128, 0).to_css_percent(), "rgb(100%, 50%, 0%)");
+        assert_eq!(rgb(250, 127, 114).to_css_percent(), "rgb(98%, 50%, 45%)");
+        assert_eq!(rgb(1, 2, 3).to_css_percent(), "rgb(0%, 1%, 1%)");
+        assert_eq!(
+            rgba(255, 128, 0, 0.0).to_css_percent(),
+            "rgba(100%, 50%, 0%, 0.00)"
+        );
+        assert_eq!(
+            rgba(0, 0, 0, 1.0).to_css_percent().parse(),
+            Ok(rgba(0, 0, 0, 1.0))
+        );
+    }
+
+    #[test]
+    fn conversion_functions_match_methods() {
+        for color in [
+            rgb(250, 128, 114),
+            rgb(8, 0, 1),
+            rgb(23, 98, 119),
+            rgb(1, 1, 255),
+        ] {
+            let [r, g, b] = color.to_f32_array();
+            let (h, s, l) = rgb_to_hsl(r, g, b);
+            let hsl = color.to_hsl();
+
+            assert!((0.0..360.0).contains(&h));
+            assert_eq!(FineAngle::from_f32(h), hsl.h);
+            assert_eq!(FineRatio::from_f32(s), hsl.s);
+            assert_eq!(FineRatio::from_f32(l), hsl.l);
+
+            let (r, g, b) = hsl_to_rgb(hsl.h.as_f32(), hsl.s.as_f32(), hsl.l.as_f32());
+            assert_eq!(
+                rgb(
+                    Ratio::from_f32(r).as_u8(),
+                    Ratio::from_f32(g).as_u8(),
+                    Ratio::from_f32(b).as_u8()
+                ),
+                hsl.to_rgb()
+            );
+        }
+    }
+
+    #[test]
+    fn conversion_functions_round_trip() {
+        let (h, s, l) = rgb_to_hsl(0.2, 0.4, 0.6);
+        let (r, g, b) = hsl_to_rgb(h, s, l);
+
+        assert!((r - 0.2).abs() < 1e-5);
+        assert!((g - 0.4).abs() < 1e-5);
+        assert!((b - 0.6).abs() < 1e-5);
+        assert_eq!(hsl_to_rgb(h - 360.0, s, l), hsl_to_rgb(h, s, l));
+    }
+
+    #[test]
+    fn conversions_are_lossless() {
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let color = rgb(r, g, b);
+                    let hsl = color.to_hsl();
+
+                    assert_eq!(hsl.to_rgb(), color);
+                    assert_eq!(hsl.to_rgb().to_hsl(), hsl);
+                }
+            }
+        }
+
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(5) {
+                for b in (0..=255).step_by(5) {
+                    let color = rgb(r, g, b);
+                    let [r, g, b] = [r, g, b].map(|c| c as f32 / 255.0);
+                    let (h, s, l) = rgb_to_hsl(r, g, b);
+                    let (r, g, b) = hsl_to_rgb(h, s, l);
+                    let channel = |c: f32| Ratio::from_u8((c * 255.0).round() as u8);
+
+                    assert_eq!(
+                        RGB {
+                            r: channel(r),
+                            g: channel(g),
+                            b: channel(b),
+                        },
+                        color
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn converts_huge_and_non_finite_hues() {
+        // Past 2^24 degrees, hues are too coarse to tell the channels' hues apart, but the
+        // conversion still finishes quickly with channels in range.
+        for hue in [1e10, -1e10, f32::MAX, f32::MIN] {
+            let (r, g, b) = hsl_to_rgb(hue, 1.0, 0.5);
+
+            assert!([r, g, b].iter().all(|c| (0.0..=1.0).contains(c)), "{}", hue);
+        }
+
+        assert_eq!(
+            hsl_to_rgb(3600.0 + 120.0, 1.0, 0.5),
+            hsl_to_rgb(120.0, 1.0, 0.5)
+        );
+
+        for hue in [f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+            assert_eq!(hsl_to_rgb(hue, 1.0, 0.5), (0.5, 0.5, 0.5));
+            assert_eq!(hsl_to_rgb(hue, 0.3, 0.2), (0.2, 0.2, 0.2));
+        }
+    }
+
+    #[test]
+    fn can_permute_channels() {
+        let salmon = rgb(250, 128, 114);
+        let translucent = rgba(250, 128, 114, 0.5);
+
+        assert_eq!(
+            salmon.rotate_channels().rotate_channels().rotate_channels(),
+            salmon
+        );
+        assert_eq!(salmon.swap_rg().swap_rg(), salmon);
+        assert_eq!(salmon.swap_gb().swap_gb(), salmon);
+        assert_eq!(
+            salmon.swap_rg().swap_gb(),
+            salmon.rotate_channels().rotate_channels()
+        );
+        assert_eq!(
+            translucent.rotate_channels().to_rgb(),
+            salmon.rotate_channels()
+        );
+        assert_eq!(
+            translucent.rotate_channels().alpha_f32(),
+            translucent.alpha_f32()
+        );
+    }
+
+    #[test]
+    fn can_replace_hsl_channels() {
+        let salmon = hsl(6, 93, 71);
+        let translucent = hsla(6, 93, 71, 0.5);
+
+        assert_eq!(salmon.with_hue(deg(-10)), hsl(350, 93, 71));
+        assert_eq!(salmon.with_saturation(percent(0)), salmon.greyscale());
+        assert_eq!(
+            salmon.with_lightness(percent(100)).to_rgb(),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(
+            translucent
+                .with_hue(deg(200))
+                .with_saturation(percent(40))
+                .with_lightness(percent(80)),
+            hsla(200, 40, 80, 0.5)
+        );
+        assert_eq!(translucent.with_hue(deg(6)), translucent);
+    }
+
     #[test]
     fn can_print_in_css() {
         let printed_rgb = format!("{}", rgb(5, 10, 255));
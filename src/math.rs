@@ -0,0 +1,46 @@
+// Floating point functions that are only provided by `std`. When building
+// without `std`, these fall back to their `libm` implementations.
+
+macro_rules! float_functions {
+    ($($name:ident => $libm:ident($($arg:ident),+);)+) => {
+        $(
+            #[cfg(feature = "std")]
+            pub(crate) fn $name($($arg: f32),+) -> f32 {
+                f32::$name($($arg),+)
+            }
+
+            #[cfg(not(feature = "std"))]
+            pub(crate) fn $name($($arg: f32),+) -> f32 {
+                libm::$libm($($arg),+)
+            }
+        )+
+    };
+}
+
+float_functions! {
+    round => roundf(value);
+    sqrt => sqrtf(value);
+    cbrt => cbrtf(value);
+    powf => powf(value, exponent);
+    exp => expf(value);
+    sin => sinf(value);
+    cos => cosf(value);
+    atan2 => atan2f(y, x);
+}
+
+// `libm` has no Euclidean remainder, so it's built from the truncated one, like `std` does.
+#[cfg(feature = "std")]
+pub(crate) fn rem_euclid(value: f32, divisor: f32) -> f32 {
+    f32::rem_euclid(value, divisor)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn rem_euclid(value: f32, divisor: f32) -> f32 {
+    let remainder = libm::fmodf(value, divisor);
+
+    if remainder < 0.0 {
+        remainder + divisor.abs()
+    } else {
+        remainder
+    }
+}
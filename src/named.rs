@@ -0,0 +1,288 @@
+use super::{rgb, RGB};
+use core::cmp::Ordering;
+
+/// Looks up a [CSS named color](https://www.w3.org/TR/css-color-4/#named-colors) by
+/// its name, ignoring ASCII case. Returns `None` for unknown names.
+///
+/// # Example
+/// ```
+/// use farver::{named_color, rgb};
+///
+/// assert_eq!(named_color("salmon"), Some(rgb(250, 128, 114)));
+/// assert_eq!(named_color("RebeccaPurple"), Some(rgb(102, 51, 153)));
+/// assert_eq!(named_color("not-a-color"), None);
+/// ```
+pub fn named_color(name: &str) -> Option<RGB> {
+    NAMED_COLORS
+        .binary_search_by(|&(candidate, _)| compare_ignoring_case(candidate, name))
+        .ok()
+        .map(|index| NAMED_COLORS[index].1)
+}
+
+// Compares a lowercase color name with `name`, ignoring the case of `name`.
+fn compare_ignoring_case(lowercase: &str, name: &str) -> Ordering {
+    lowercase
+        .bytes()
+        .cmp(name.bytes().map(|byte| byte.to_ascii_lowercase()))
+}
+
+/// The 16 basic colors of the [CSS Color Module](https://www.w3.org/TR/css-color-4/#named-colors),
+/// for when a color is known up front and its name reads better than its channels.
+/// They are also available by name through [`named_color`].
+///
+/// # Example
+/// ```
+/// use farver::{named_color, rgb, RGB};
+///
+/// assert_eq!(RGB::RED, rgb(255, 0, 0));
+/// assert_eq!(Some(RGB::TEAL), named_color("teal"));
+/// ```
+impl RGB {
+    /// The CSS named color `black`, `rgb(0, 0, 0)`.
+    pub const BLACK: RGB = rgb(0, 0, 0);
+    /// The CSS named color `silver`, `rgb(192, 192, 192)`.
+    pub const SILVER: RGB = rgb(192, 192, 192);
+    /// The CSS named color `gray`, `rgb(128, 128, 128)`.
+    pub const GRAY: RGB = rgb(128, 128, 128);
+    /// The CSS named color `white`, `rgb(255, 255, 255)`.
+    pub const WHITE: RGB = rgb(255, 255, 255);
+    /// The CSS named color `maroon`, `rgb(128, 0, 0)`.
+    pub const MAROON: RGB = rgb(128, 0, 0);
+    /// The CSS named color `red`, `rgb(255, 0, 0)`.
+    pub const RED: RGB = rgb(255, 0, 0);
+    /// The CSS named color `purple`, `rgb(128, 0, 128)`.
+    pub const PURPLE: RGB = rgb(128, 0, 128);
+    /// The CSS named color `fuchsia`, `rgb(255, 0, 255)`.
+    pub const FUCHSIA: RGB = rgb(255, 0, 255);
+    /// The CSS named color `green`, `rgb(0, 128, 0)`.
+    pub const GREEN: RGB = rgb(0, 128, 0);
+    /// The CSS named color `lime`, `rgb(0, 255, 0)`.
+    pub const LIME: RGB = rgb(0, 255, 0);
+    /// The CSS named color `olive`, `rgb(128, 128, 0)`.
+    pub const OLIVE: RGB = rgb(128, 128, 0);
+    /// The CSS named color `yellow`, `rgb(255, 255, 0)`.
+    pub const YELLOW: RGB = rgb(255, 255, 0);
+    /// The CSS named color `navy`, `rgb(0, 0, 128)`.
+    pub const NAVY: RGB = rgb(0, 0, 128);
+    /// The CSS named color `blue`, `rgb(0, 0, 255)`.
+    pub const BLUE: RGB = rgb(0, 0, 255);
+    /// The CSS named color `teal`, `rgb(0, 128, 128)`.
+    pub const TEAL: RGB = rgb(0, 128, 128);
+    /// The CSS named color `aqua`, `rgb(0, 255, 255)`.
+    pub const AQUA: RGB = rgb(0, 255, 255);
+}
+
+/// The 148 named colors defined by the [CSS Color Module](https://www.w3.org/TR/css-color-4/#named-colors),
+/// as lowercase names paired with their colors. The table is sorted by name, so it can be
+/// searched with [`slice::binary_search_by`]; [`named_color`] does exactly that.
+/// Synonyms such as `aqua` and `cyan`, or `gray` and `grey`, each have their own entry.
+pub const NAMED_COLORS: &[(&str, RGB)] = &[
+    ("aliceblue", rgb(240, 248, 255)),
+    ("antiquewhite", rgb(250, 235, 215)),
+    ("aqua", rgb(0, 255, 255)),
+    ("aquamarine", rgb(127, 255, 212)),
+    ("azure", rgb(240, 255, 255)),
+    ("beige", rgb(245, 245, 220)),
+    ("bisque", rgb(255, 228, 196)),
+    ("black", rgb(0, 0, 0)),
+    ("blanchedalmond", rgb(255, 235, 205)),
+    ("blue", rgb(0, 0, 255)),
+    ("blueviolet", rgb(138, 43, 226)),
+    ("brown", rgb(165, 42, 42)),
+    ("burlywood", rgb(222, 184, 135)),
+    ("cadetblue", rgb(95, 158, 160)),
+    ("chartreuse", rgb(127, 255, 0)),
+    ("chocolate", rgb(210, 105, 30)),
+    ("coral", rgb(255, 127, 80)),
+    ("cornflowerblue", rgb(100, 149, 237)),
+    ("cornsilk", rgb(255, 248, 220)),
+    ("crimson", rgb(220, 20, 60)),
+    ("cyan", rgb(0, 255, 255)),
+    ("darkblue", rgb(0, 0, 139)),
+    ("darkcyan", rgb(0, 139, 139)),
+    ("darkgoldenrod", rgb(184, 134, 11)),
+    ("darkgray", rgb(169, 169, 169)),
+    ("darkgreen", rgb(0, 100, 0)),
+    ("darkgrey", rgb(169, 169, 169)),
+    ("darkkhaki", rgb(189, 183, 107)),
+    ("darkmagenta", rgb(139, 0, 139)),
+    ("darkolivegreen", rgb(85, 107, 47)),
+    ("darkorange", rgb(255, 140, 0)),
+    ("darkorchid", rgb(153, 50, 204)),
+    ("darkred", rgb(139, 0, 0)),
+    ("darksalmon", rgb(233, 150, 122)),
+    ("darkseagreen", rgb(143, 188, 143)),
+    ("darkslateblue", rgb(72, 61, 139)),
+    ("darkslategray", rgb(47, 79, 79)),
+    ("darkslategrey", rgb(47, 79, 79)),
+    ("darkturquoise", rgb(0, 206, 209)),
+    ("darkviolet", rgb(148, 0, 211)),
+    ("deeppink", rgb(255, 20, 147)),
+    ("deepskyblue", rgb(0, 191, 255)),
+    ("dimgray", rgb(105, 105, 105)),
+    ("dimgrey", rgb(105, 105, 105)),
+    ("dodgerblue", rgb(30, 144, 255)),
+    ("firebrick", rgb(178, 34, 34)),
+    ("floralwhite", rgb(255, 250, 240)),
+    ("forestgreen", rgb(34, 139, 34)),
+    ("fuchsia", rgb(255, 0, 255)),
+    ("gainsboro", rgb(220, 220, 220)),
+    ("ghostwhite", rgb(248, 248, 255)),
+    ("gold", rgb(255, 215, 0)),
+    ("goldenrod", rgb(218, 165, 32)),
+    ("gray", rgb(128, 128, 128)),
+    ("green", rgb(0, 128, 0)),
+    ("greenyellow", rgb(173, 255, 47)),
+    ("grey", rgb(128, 128, 128)),
+    ("honeydew", rgb(240, 255, 240)),
+    ("hotpink", rgb(255, 105, 180)),
+    ("indianred", rgb(205, 92, 92)),
+    ("indigo", rgb(75, 0, 130)),
+    ("ivory", rgb(255, 255, 240)),
+    ("khaki", rgb(240, 230, 140)),
+    ("lavender", rgb(230, 230, 250)),
+    ("lavenderblush", rgb(255, 240, 245)),
+    ("lawngreen", rgb(124, 252, 0)),
+    ("lemonchiffon", rgb(255, 250, 205)),
+    ("lightblue", rgb(173, 216, 230)),
+    ("lightcoral", rgb(240, 128, 128)),
+    ("lightcyan", rgb(224, 255, 255)),
+    ("lightgoldenrodyellow", rgb(250, 250, 210)),
+    ("lightgray", rgb(211, 211, 211)),
+    ("lightgreen", rgb(144, 238, 144)),
+    ("lightgrey", rgb(211, 211, 211)),
+    ("lightpink", rgb(255, 182, 193)),
+    ("lightsalmon", rgb(255, 160, 122)),
+    ("lightseagreen", rgb(32, 178, 170)),
+    ("lightskyblue", rgb(135, 206, 250)),
+    ("lightslategray", rgb(119, 136, 153)),
+    ("lightslategrey", rgb(119, 136, 153)),
+    ("lightsteelblue", rgb(176, 196, 222)),
+    ("lightyellow", rgb(255, 255, 224)),
+    ("lime", rgb(0, 255, 0)),
+    ("limegreen", rgb(50, 205, 50)),
+    ("linen", rgb(250, 240, 230)),
+    ("magenta", rgb(255, 0, 255)),
+    ("maroon", rgb(128, 0, 0)),
+    ("mediumaquamarine", rgb(102, 205, 170)),
+    ("mediumblue", rgb(0, 0, 205)),
+    ("mediumorchid", rgb(186, 85, 211)),
+    ("mediumpurple", rgb(147, 112, 219)),
+    ("mediumseagreen", rgb(60, 179, 113)),
+    ("mediumslateblue", rgb(123, 104, 238)),
+    ("mediumspringgreen", rgb(0, 250, 154)),
+    ("mediumturquoise", rgb(72, 209, 204)),
+    ("mediumvioletred", rgb(199, 21, 133)),
+    ("midnightblue", rgb(25, 25, 112)),
+    ("mintcream", rgb(245, 255, 250)),
+    ("mistyrose", rgb(255, 228, 225)),
+    ("moccasin", rgb(255, 228, 181)),
+    ("navajowhite", rgb(255, 222, 173)),
+    ("navy", rgb(0, 0, 128)),
+    ("oldlace", rgb(253, 245, 230)),
+    ("olive", rgb(128, 128, 0)),
+    ("olivedrab", rgb(107, 142, 35)),
+    ("orange", rgb(255, 165, 0)),
+    ("orangered", rgb(255, 69, 0)),
+    ("orchid", rgb(218, 112, 214)),
+    ("palegoldenrod", rgb(238, 232, 170)),
+    ("palegreen", rgb(152, 251, 152)),
+    ("paleturquoise", rgb(175, 238, 238)),
+    ("palevioletred", rgb(219, 112, 147)),
+    ("papayawhip", rgb(255, 239, 213)),
+    ("peachpuff", rgb(255, 218, 185)),
+    ("peru", rgb(205, 133, 63)),
+    ("pink", rgb(255, 192, 203)),
+    ("plum", rgb(221, 160, 221)),
+    ("powderblue", rgb(176, 224, 230)),
+    ("purple", rgb(128, 0, 128)),
+    ("rebeccapurple", rgb(102, 51, 153)),
+    ("red", rgb(255, 0, 0)),
+    ("rosybrown", rgb(188, 143, 143)),
+    ("royalblue", rgb(65, 105, 225)),
+    ("saddlebrown", rgb(139, 69, 19)),
+    ("salmon", rgb(250, 128, 114)),
+    ("sandybrown", rgb(244, 164, 96)),
+    ("seagreen", rgb(46, 139, 87)),
+    ("seashell", rgb(255, 245, 238)),
+    ("sienna", rgb(160, 82, 45)),
+    ("silver", rgb(192, 192, 192)),
+    ("skyblue", rgb(135, 206, 235)),
+    ("slateblue", rgb(106, 90, 205)),
+    ("slategray", rgb(112, 128, 144)),
+    ("slategrey", rgb(112, 128, 144)),
+    ("snow", rgb(255, 250, 250)),
+    ("springgreen", rgb(0, 255, 127)),
+    ("steelblue", rgb(70, 130, 180)),
+    ("tan", rgb(210, 180, 140)),
+    ("teal", rgb(0, 128, 128)),
+    ("thistle", rgb(216, 191, 216)),
+    ("tomato", rgb(255, 99, 71)),
+    ("turquoise", rgb(64, 224, 208)),
+    ("violet", rgb(238, 130, 238)),
+    ("wheat", rgb(245, 222, 179)),
+    ("white", rgb(255, 255, 255)),
+    ("whitesmoke", rgb(245, 245, 245)),
+    ("yellow", rgb(255, 255, 0)),
+    ("yellowgreen", rgb(154, 205, 50)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{named_color, NAMED_COLORS};
+    use crate::{rgb, RGB};
+
+    #[test]
+    fn has_every_named_color() {
+        assert_eq!(NAMED_COLORS.len(), 148);
+    }
+
+    #[test]
+    fn is_sorted_by_name() {
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn looks_up_every_name() {
+        for &(name, color) in NAMED_COLORS {
+            assert_eq!(named_color(name), Some(color));
+            assert_eq!(named_color(&name.to_uppercase()), Some(color));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert_eq!(named_color(""), None);
+        assert_eq!(named_color("salmo"), None);
+        assert_eq!(named_color("salmonn"), None);
+        assert_eq!(named_color(" salmon"), None);
+        assert_eq!(named_color("transparent"), None);
+        assert_eq!(named_color("DarkSlateGrey"), Some(rgb(47, 79, 79)));
+    }
+
+    #[test]
+    fn has_consts_for_the_basic_colors() {
+        let basics = [
+            ("black", RGB::BLACK),
+            ("silver", RGB::SILVER),
+            ("gray", RGB::GRAY),
+            ("white", RGB::WHITE),
+            ("maroon", RGB::MAROON),
+            ("red", RGB::RED),
+            ("purple", RGB::PURPLE),
+            ("fuchsia", RGB::FUCHSIA),
+            ("green", RGB::GREEN),
+            ("lime", RGB::LIME),
+            ("olive", RGB::OLIVE),
+            ("yellow", RGB::YELLOW),
+            ("navy", RGB::NAVY),
+            ("blue", RGB::BLUE),
+            ("teal", RGB::TEAL),
+            ("aqua", RGB::AQUA),
+        ];
+
+        for (name, color) in basics {
+            assert_eq!(named_color(name), Some(color));
+        }
+    }
+}
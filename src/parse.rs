@@ -0,0 +1,717 @@
+use super::{deg, named_color, percent, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use crate::fine::FineRatio;
+use crate::math::round;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// An error which can be returned when parsing a color from a string.
+pub enum ParseColorError {
+    /// The input does not match any of the supported color formats.
+    UnrecognizedFormat,
+
+    /// A channel value falls outside of its valid range, such as a
+    /// red channel of `300` or a saturation of `120%`.
+    ChannelOutOfRange,
+
+    /// A hex color contains a character that is not a hex digit.
+    InvalidHexDigit(char),
+
+    /// The input is a CSS keyword that stands in for a color but can't be
+    /// resolved without a stylesheet, such as `currentColor` or `inherit`.
+    /// Holds the keyword as it was written, so that it can be preserved.
+    Keyword(String),
+
+    /// An entry of a list of colors could not be parsed. The `index`
+    /// counts the non-blank entries preceding the one that failed.
+    InvalidListItem {
+        index: usize,
+        error: Box<ParseColorError>,
+    },
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::UnrecognizedFormat => write!(f, "unrecognized color format"),
+            ParseColorError::ChannelOutOfRange => write!(f, "color channel out of range"),
+            ParseColorError::InvalidHexDigit(c) => write!(f, "invalid hex digit {:?}", c),
+            ParseColorError::Keyword(keyword) => {
+                write!(f, "unresolvable color keyword {:?}", keyword)
+            }
+            ParseColorError::InvalidListItem { index, error } => {
+                write!(f, "invalid color at index {}: {}", index, error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseColorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseColorError::InvalidListItem { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a color written either in hex notation (`#rgb`, `#rgba`, `#rrggbb`
+/// or `#rrggbbaa`), with one of the `rgb()`, `rgba()`, `hsl()` and `hsla()`
+/// CSS functions, as one of the [named colors](crate::NAMED_COLORS), or as the
+/// `transparent` keyword (fully transparent black).
+///
+/// The functions accept both the comma separated syntax and the space separated
+/// syntax of CSS Color Module Level 4, such as `rgb(100% 50% 0% / 50%)`. The red,
+/// green and blue channels can be given as numbers within `0-255` or as percentages,
+/// and the hue as a number of degrees or with a `deg`, `grad`, `rad` or `turn` unit.
+/// Numbers and percentages may have a fractional part, and are rounded to the
+/// nearest value the channel can hold.
+///
+/// Keywords that can't be resolved to a color on their own, such as
+/// `currentColor`, `inherit`, `initial`, `unset` and `revert`, return a
+/// [`ParseColorError::Keyword`] error holding the keyword.
+///
+/// # Example
+/// ```
+/// use farver::{parse, rgba, ParseColorError};
+///
+/// assert_eq!(parse("#fa8072"), Ok(rgba(250, 128, 114, 1.0)));
+/// assert_eq!(parse("rgba(250, 128, 114, 0.5)"), Ok(rgba(250, 128, 114, 0.5)));
+/// assert_eq!(parse("rgb(100% 50% 0% / 0.5)"), Ok(rgba(255, 128, 0, 0.5)));
+/// assert_eq!(parse("transparent"), Ok(rgba(0, 0, 0, 0.0)));
+/// assert_eq!(parse("Salmon"), Ok(rgba(250, 128, 114, 1.0)));
+/// assert!(parse("#fa807").is_err());
+/// assert_eq!(
+///     parse("currentColor"),
+///     Err(ParseColorError::Keyword(String::from("currentColor")))
+/// );
+/// ```
+pub fn parse(input: &str) -> Result<RGBA, ParseColorError> {
+    input.parse()
+}
+
+/// Parses a list of colors separated by commas or newlines, using the same
+/// formats as [`parse`]. Blank entries are skipped, and commas within
+/// parentheses (such as those of `rgb(250, 128, 114)`) don't separate entries.
+///
+/// If an entry can't be parsed, the error returned is a
+/// [`ParseColorError::InvalidListItem`] holding the index of that entry.
+///
+/// # Example
+/// ```
+/// use farver::{parse_list, rgba, ParseColorError};
+///
+/// assert_eq!(
+///     parse_list("#fa8072, rgb(5, 10, 255)\n#000"),
+///     Ok(vec![
+///         rgba(250, 128, 114, 1.0),
+///         rgba(5, 10, 255, 1.0),
+///         rgba(0, 0, 0, 1.0),
+///     ])
+/// );
+/// assert!(matches!(
+///     parse_list("#fa8072, #fa807"),
+///     Err(ParseColorError::InvalidListItem { index: 1, .. })
+/// ));
+/// ```
+pub fn parse_list(input: &str) -> Result<Vec<RGBA>, ParseColorError> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push(&input[start..i]);
+                start = i + 1;
+            }
+            '\n' => {
+                entries.push(&input[start..i]);
+                start = i + 1;
+                depth = 0;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&input[start..]);
+
+    entries
+        .into_iter()
+        .filter(|entry| !entry.trim().is_empty())
+        .enumerate()
+        .map(|(index, entry)| {
+            parse(entry).map_err(|error| ParseColorError::InvalidListItem {
+                index,
+                error: Box::new(error),
+            })
+        })
+        .collect()
+}
+
+// The color model a string was written in, so that parsing into the same
+// model doesn't need a lossy round-trip through another one.
+enum ParsedColor {
+    Rgba(RGBA),
+    Hsla(HSLA),
+}
+
+// Keywords which stand in for a color that depends on the surrounding stylesheet.
+const KEYWORDS: &[&str] = &[
+    "currentColor",
+    "inherit",
+    "initial",
+    "unset",
+    "revert",
+    "revert-layer",
+];
+
+// Parses either a hex color (`#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`), one
+// of the `rgb()`, `rgba()`, `hsl()` and `hsla()` functions, `transparent`, or
+// a named color.
+fn parse_color(input: &str) -> Result<ParsedColor, ParseColorError> {
+    let input = input.trim();
+
+    if input.eq_ignore_ascii_case("transparent") {
+        return Ok(ParsedColor::Rgba(RGBA {
+            r: percent(0),
+            g: percent(0),
+            b: percent(0),
+            a: percent(0),
+        }));
+    }
+
+    if KEYWORDS
+        .iter()
+        .any(|keyword| input.eq_ignore_ascii_case(keyword))
+    {
+        return Err(ParseColorError::Keyword(input.to_string()));
+    }
+
+    match input.strip_prefix('#') {
+        Some(hex) => parse_hex(hex).map(ParsedColor::Rgba),
+        None => parse_function(input).or_else(|error| match named_color(input) {
+            Some(color) => Ok(ParsedColor::Rgba(color.to_rgba())),
+            None => Err(error),
+        }),
+    }
+}
+
+pub(crate) fn parse_hex(hex: &str) -> Result<RGBA, ParseColorError> {
+    let digits = hex
+        .chars()
+        .map(|c| {
+            c.to_digit(16)
+                .map(|digit| digit as u8)
+                .ok_or(ParseColorError::InvalidHexDigit(c))
+        })
+        .collect::<Result<Vec<u8>, ParseColorError>>()?;
+
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|d| d * 17).collect(),
+        6 | 8 => digits.chunks(2).map(|d| d[0] * 16 + d[1]).collect(),
+        _ => return Err(ParseColorError::UnrecognizedFormat),
+    };
+
+    Ok(RGBA {
+        r: Ratio::from_u8(channels[0]),
+        g: Ratio::from_u8(channels[1]),
+        b: Ratio::from_u8(channels[2]),
+        a: Ratio::from_u8(channels.get(3).copied().unwrap_or(255)),
+    })
+}
+
+fn parse_function(input: &str) -> Result<ParsedColor, ParseColorError> {
+    let (name, args) = input
+        .strip_suffix(')')
+        .and_then(|input| input.split_once('('))
+        .ok_or(ParseColorError::UnrecognizedFormat)?;
+
+    let name = name.trim();
+    let is = |expected: &str| name.eq_ignore_ascii_case(expected);
+
+    // Without commas, the arguments use the space separated syntax of CSS Color
+    // Module Level 4, in which `rgb()` and `rgba()` (or `hsl()` and `hsla()`) are
+    // interchangeable and the alpha value is optional.
+    let modern = !args.contains(',');
+    let args = if modern {
+        split_modern_args(args)?
+    } else {
+        args.split(',').map(str::trim).collect()
+    };
+
+    match args.as_slice() {
+        [r, g, b] if is("rgb") || modern && is("rgba") => Ok(ParsedColor::Rgba(RGBA {
+            r: parse_rgb_channel(r)?,
+            g: parse_rgb_channel(g)?,
+            b: parse_rgb_channel(b)?,
+            a: percent(100),
+        })),
+        [r, g, b, a] if is("rgba") || modern && is("rgb") => Ok(ParsedColor::Rgba(RGBA {
+            r: parse_rgb_channel(r)?,
+            g: parse_rgb_channel(g)?,
+            b: parse_rgb_channel(b)?,
+            a: parse_alpha(a)?,
+        })),
+        [h, s, l] if is("hsl") || modern && is("hsla") => Ok(ParsedColor::Hsla(HSLA {
+            h: parse_hue(h)?.into(),
+            s: parse_percentage(s)?,
+            l: parse_percentage(l)?,
+            a: percent(100),
+        })),
+        [h, s, l, a] if is("hsla") || modern && is("hsl") => Ok(ParsedColor::Hsla(HSLA {
+            h: parse_hue(h)?.into(),
+            s: parse_percentage(s)?,
+            l: parse_percentage(l)?,
+            a: parse_alpha(a)?,
+        })),
+        _ => Err(ParseColorError::UnrecognizedFormat),
+    }
+}
+
+// Splits space separated arguments, such as `250 128 114 / 50%`, into the three
+// channels followed by the alpha value, if any.
+fn split_modern_args(args: &str) -> Result<Vec<&str>, ParseColorError> {
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (args, None),
+    };
+
+    let mut args: Vec<&str> = channels.split_whitespace().collect();
+
+    if args.len() != 3 {
+        return Err(ParseColorError::UnrecognizedFormat);
+    }
+
+    args.extend(alpha);
+
+    Ok(args)
+}
+
+fn parse_float(value: &str) -> Result<f32, ParseColorError> {
+    value
+        .parse()
+        .ok()
+        .filter(|value: &f32| value.is_finite())
+        .ok_or(ParseColorError::UnrecognizedFormat)
+}
+
+// Parses either a number within `0-255`, or a percentage mapping `0-100%` onto it.
+fn parse_rgb_channel(value: &str) -> Result<Ratio, ParseColorError> {
+    if let Some(value) = value.strip_suffix('%') {
+        return match parse_float(value)? {
+            value if (0.0..=100.0).contains(&value) => Ok(Ratio::from_f32(value / 100.0)),
+            _ => Err(ParseColorError::ChannelOutOfRange),
+        };
+    }
+
+    match parse_float(value)? {
+        value if (0.0..=255.0).contains(&value) => Ok(Ratio::from_f32(value / 255.0)),
+        _ => Err(ParseColorError::ChannelOutOfRange),
+    }
+}
+
+// Parses a hue given in degrees, either as a bare number or with one of the
+// `deg`, `grad`, `rad` or `turn` units.
+fn parse_hue(value: &str) -> Result<Angle, ParseColorError> {
+    // `grad` comes before `rad`, which it ends with.
+    const UNITS: [(&str, f32); 4] = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    let (value, degrees_per_unit) = UNITS
+        .iter()
+        .find_map(|&(unit, degrees_per_unit)| {
+            let split = value.len().checked_sub(unit.len())?;
+
+            value
+                .get(split..)
+                .filter(|suffix| suffix.eq_ignore_ascii_case(unit))
+                .map(|_| (&value[..split], degrees_per_unit))
+        })
+        .unwrap_or((value, 1.0));
+
+    Ok(deg(round(parse_float(value)? * degrees_per_unit) as i32))
+}
+
+fn parse_percentage(value: &str) -> Result<FineRatio, ParseColorError> {
+    let value = value
+        .strip_suffix('%')
+        .ok_or(ParseColorError::UnrecognizedFormat)?;
+
+    match parse_float(value)? {
+        value if (0.0..=100.0).contains(&value) => {
+            Ok(FineRatio::from_percentage(round(value) as u8))
+        }
+        _ => Err(ParseColorError::ChannelOutOfRange),
+    }
+}
+
+// Parses either a number within `0.0-1.0`, or a percentage mapping `0-100%` onto it.
+fn parse_alpha(value: &str) -> Result<Ratio, ParseColorError> {
+    let value = match value.strip_suffix('%') {
+        Some(value) => parse_float(value)? / 100.0,
+        None => parse_float(value)?,
+    };
+
+    if (0.0..=1.0).contains(&value) {
+        Ok(Ratio::from_f32(value))
+    } else {
+        Err(ParseColorError::ChannelOutOfRange)
+    }
+}
+
+macro_rules! impl_from_str {
+    ($x:ident, $to:ident) => {
+        impl FromStr for $x {
+            type Err = ParseColorError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match parse_color(s)? {
+                    ParsedColor::Rgba(color) => Ok(color.$to()),
+                    ParsedColor::Hsla(color) => Ok(color.$to()),
+                }
+            }
+        }
+
+        impl TryFrom<&str> for $x {
+            type Error = ParseColorError;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+    };
+}
+
+impl_from_str!(RGB, to_rgb);
+impl_from_str!(RGBA, to_rgba);
+impl_from_str!(HSL, to_hsl);
+impl_from_str!(HSLA, to_hsla);
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_list, ParseColorError};
+    use crate::{hsl, hsla, rgb, rgba, Color, HSL, HSLA, RGB, RGBA};
+
+    #[test]
+    fn can_parse_hex() {
+        assert_eq!("#fa8072".parse(), Ok(rgb(250, 128, 114)));
+        assert_eq!("#FA8072".parse(), Ok(rgb(250, 128, 114)));
+        assert_eq!("#fa807200".parse(), Ok(rgba(250, 128, 114, 0.0)));
+        assert_eq!("#fa0".parse(), Ok(rgb(255, 170, 0)));
+        assert_eq!("#fa0f".parse(), Ok(rgba(255, 170, 0, 1.0)));
+    }
+
+    #[test]
+    fn can_parse_functions() {
+        assert_eq!("rgb(250, 128, 114)".parse(), Ok(rgb(250, 128, 114)));
+        assert_eq!(
+            "rgba(250, 128, 114, 0.5)".parse(),
+            Ok(rgba(250, 128, 114, 0.5))
+        );
+        assert_eq!("hsl(6, 93%, 71%)".parse(), Ok(hsl(6, 93, 71)));
+        assert_eq!("hsla(6, 93%, 71%, 0.5)".parse(), Ok(hsla(6, 93, 71, 0.5)));
+        assert_eq!(" RGB( 5,10 , 255 ) ".parse(), Ok(rgb(5, 10, 255)));
+    }
+
+    #[test]
+    fn can_parse_modern_syntax() {
+        assert_eq!("rgb(250 128 114)".parse(), Ok(rgb(250, 128, 114)));
+        assert_eq!(
+            "rgb(250 128 114 / 0.5)".parse(),
+            Ok(rgba(250, 128, 114, 0.5))
+        );
+        assert_eq!(
+            "rgba( 250  128 114/50% )".parse(),
+            Ok(rgba(250, 128, 114, 0.5))
+        );
+        assert_eq!("rgba(250 128 114)".parse(), Ok(rgb(250, 128, 114)));
+        assert_eq!("hsl(6 93% 71%)".parse(), Ok(hsl(6, 93, 71)));
+        assert_eq!("hsl(6 93% 71% / 0.5)".parse(), Ok(hsla(6, 93, 71, 0.5)));
+        assert_eq!("hsla(6 93% 71%)".parse(), Ok(hsl(6, 93, 71)));
+    }
+
+    #[test]
+    fn can_parse_rgb_percentages() {
+        assert_eq!("rgb(100% 50% 0%)".parse(), Ok(rgb(255, 128, 0)));
+        assert_eq!("rgb(100%, 50%, 0%)".parse(), Ok(rgb(255, 128, 0)));
+        assert_eq!(
+            "rgba(0%, 100%, 25%, 0.5)".parse(),
+            Ok(rgba(0, 255, 64, 0.5))
+        );
+        assert_eq!("rgb(12.5% 255 0)".parse(), Ok(rgb(32, 255, 0)));
+    }
+
+    #[test]
+    fn can_parse_fractional_numbers() {
+        assert_eq!("rgb(1.5, 2, 3)".parse(), Ok(rgb(2, 2, 3)));
+        assert_eq!("rgb(250.4 127.6 114)".parse(), Ok(rgb(250, 128, 114)));
+        assert_eq!("hsl(6, 93.5%, 71%)".parse(), Ok(hsl(6, 94, 71)));
+        assert_eq!("hsl(6, 93.4%, 71%)".parse(), Ok(hsl(6, 93, 71)));
+        assert_eq!("hsl(6 92.5% 70.6%)".parse(), Ok(hsl(6, 93, 71)));
+        assert_eq!(
+            RGBA::try_from("rgb(255.5, 0, 0)"),
+            Err(ParseColorError::ChannelOutOfRange)
+        );
+        assert_eq!(
+            RGBA::try_from("hsl(6, 100.5%, 71%)"),
+            Err(ParseColorError::ChannelOutOfRange)
+        );
+    }
+
+    #[test]
+    fn can_parse_named_colors() {
+        assert_eq!("red".parse(), Ok(rgb(255, 0, 0)));
+        assert_eq!(" RebeccaPurple ".parse(), Ok(rgba(102, 51, 153, 1.0)));
+        assert_eq!(RGB::try_from("salmon"), Ok(rgb(250, 128, 114)));
+        assert_eq!(HSL::try_from("red"), Ok(hsl(0, 100, 50)));
+        assert_eq!(
+            parse_list("red, lime\nblue"),
+            Ok(vec![
+                rgba(255, 0, 0, 1.0),
+                rgba(0, 255, 0, 1.0),
+                rgba(0, 0, 255, 1.0),
+            ])
+        );
+        assert_eq!(
+            RGBA::try_from("not-a-color"),
+            Err(ParseColorError::UnrecognizedFormat)
+        );
+    }
+
+    #[test]
+    fn can_parse_hue_units() {
+        assert_eq!("hsl(6deg 93% 71%)".parse(), Ok(hsl(6, 93, 71)));
+        assert_eq!("hsl(6DEG, 93%, 71%)".parse(), Ok(hsl(6, 93, 71)));
+        assert_eq!("hsl(0.5turn 93% 71%)".parse(), Ok(hsl(180, 93, 71)));
+        assert_eq!("hsl(3.14159rad 93% 71%)".parse(), Ok(hsl(180, 93, 71)));
+        assert_eq!("hsl(200grad 93% 71%)".parse(), Ok(hsl(180, 93, 71)));
+        assert_eq!("hsl(-0.25turn 93% 71%)".parse(), Ok(hsl(270, 93, 71)));
+        assert_eq!("hsl(6.4 93% 71%)".parse(), Ok(hsl(6, 93, 71)));
+    }
+
+    #[test]
+    fn handles_invalid_modern_syntax() {
+        for input in [
+            "rgb(1 2)",
+            "rgb(1 2 / 0.5)",
+            "rgb(1 2 3 4)",
+            "rgb(1 2 3 / 0.5 0.5)",
+            "rgb(1 2 3 /)",
+            "rgb()",
+            "rgb(1% 2 3px)",
+            "hsl(6turns 93% 71%)",
+            "hsl(inf 93% 71%)",
+            "hsl(6° 93% 71%)",
+        ] {
+            assert_eq!(
+                RGBA::try_from(input),
+                Err(ParseColorError::UnrecognizedFormat),
+                "{}",
+                input
+            );
+        }
+
+        for input in ["rgb(101% 0% 0%)", "rgb(0 0 0 / 101%)", "rgb(-1% 0 0)"] {
+            assert_eq!(
+                RGBA::try_from(input),
+                Err(ParseColorError::ChannelOutOfRange),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn can_parse_transparent() {
+        assert_eq!("transparent".parse(), Ok(rgba(0, 0, 0, 0.0)));
+        assert_eq!(" Transparent ".parse(), Ok(rgba(0, 0, 0, 0.0)));
+        assert_eq!("TRANSPARENT".parse(), Ok(hsla(0, 0, 0, 0.0)));
+        assert_eq!("transparent".parse(), Ok(rgb(0, 0, 0)));
+        assert_eq!(
+            parse_list("transparent, #000"),
+            Ok(vec![rgba(0, 0, 0, 0.0), rgba(0, 0, 0, 1.0)])
+        );
+        assert_eq!(
+            RGBA::try_from("transparentt"),
+            Err(ParseColorError::UnrecognizedFormat)
+        );
+    }
+
+    #[test]
+    fn handles_keywords() {
+        for input in [
+            "currentColor",
+            "currentcolor",
+            "inherit",
+            "INITIAL",
+            "unset",
+            "revert",
+            "revert-layer",
+        ] {
+            assert_eq!(
+                RGBA::try_from(input),
+                Err(ParseColorError::Keyword(input.to_string())),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            HSL::try_from(" currentColor "),
+            Err(ParseColorError::Keyword("currentColor".to_string()))
+        );
+        assert_eq!(
+            parse_list("#000, inherit"),
+            Err(ParseColorError::InvalidListItem {
+                index: 1,
+                error: Box::new(ParseColorError::Keyword("inherit".to_string())),
+            })
+        );
+    }
+
+    #[test]
+    fn can_parse_into_other_models() {
+        assert_eq!("#ff0000".parse(), Ok(hsl(0, 100, 50)));
+        assert_eq!("rgba(255, 0, 0, 0.5)".parse(), Ok(hsla(0, 100, 50, 0.5)));
+        assert_eq!("hsl(9, 100%, 64%)".parse(), Ok(hsl(9, 100, 64).to_rgb()));
+        assert_eq!(
+            "hsla(9, 100%, 64%, 0.5)".parse(),
+            Ok(hsla(9, 100, 64, 0.5).to_rgba())
+        );
+    }
+
+    #[test]
+    fn can_try_from_str() {
+        assert_eq!(RGB::try_from("#fa8072"), Ok(rgb(250, 128, 114)));
+        assert_eq!(RGBA::try_from("#fa807280"), Ok(rgba(250, 128, 114, 0.5)));
+        assert_eq!(HSL::try_from("hsl(6, 93%, 71%)"), Ok(hsl(6, 93, 71)));
+        assert_eq!(
+            HSLA::try_from("hsla(6, 93%, 71%, 1.0)"),
+            Ok(hsla(6, 93, 71, 1.0))
+        );
+    }
+
+    #[test]
+    fn handles_unrecognized_formats() {
+        for input in [
+            "",
+            "fa8072",
+            "#fa807",
+            "#fa80721",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3, 0.5)",
+            "rgba(1, 2, 3)",
+            "cmyk(1, 2, 3, 4)",
+            "hsl(6, 93, 71)",
+            "rgb(1, 2, 3",
+        ] {
+            assert_eq!(
+                RGBA::try_from(input),
+                Err(ParseColorError::UnrecognizedFormat),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn handles_channels_out_of_range() {
+        for input in [
+            "rgb(256, 0, 0)",
+            "rgb(0, -1, 0)",
+            "rgba(0, 0, 0, 1.5)",
+            "hsl(6, 101%, 71%)",
+            "hsla(6, 93%, 71%, -0.5)",
+        ] {
+            assert_eq!(
+                RGBA::try_from(input),
+                Err(ParseColorError::ChannelOutOfRange),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn handles_invalid_hex_digits() {
+        assert_eq!(
+            RGB::try_from("#fa807g"),
+            Err(ParseColorError::InvalidHexDigit('g'))
+        );
+        assert_eq!(
+            RGB::try_from("#fa80é2"),
+            Err(ParseColorError::InvalidHexDigit('é'))
+        );
+    }
+
+    #[test]
+    fn can_parse_lists() {
+        assert_eq!(parse_list(""), Ok(vec![]));
+        assert_eq!(
+            parse_list("#fa8072,#000\n\nrgba(5, 10, 255, 0.5),,\n hsl(0, 0%, 100%) \n"),
+            Ok(vec![
+                rgba(250, 128, 114, 1.0),
+                rgba(0, 0, 0, 1.0),
+                rgba(5, 10, 255, 0.5),
+                rgba(255, 255, 255, 1.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn handles_invalid_list_items() {
+        assert_eq!(
+            parse_list("#fa8072,\n, #000, rgb(256, 0, 0), #zzz"),
+            Err(ParseColorError::InvalidListItem {
+                index: 2,
+                error: Box::new(ParseColorError::ChannelOutOfRange),
+            })
+        );
+        assert_eq!(
+            parse_list("rgb(1, 2, 3\n4)"),
+            Err(ParseColorError::InvalidListItem {
+                index: 0,
+                error: Box::new(ParseColorError::UnrecognizedFormat),
+            })
+        );
+    }
+
+    #[test]
+    fn can_display_errors() {
+        assert_eq!(
+            ParseColorError::UnrecognizedFormat.to_string(),
+            "unrecognized color format"
+        );
+        assert_eq!(
+            ParseColorError::ChannelOutOfRange.to_string(),
+            "color channel out of range"
+        );
+        assert_eq!(
+            ParseColorError::InvalidHexDigit('g').to_string(),
+            "invalid hex digit 'g'"
+        );
+        assert_eq!(
+            ParseColorError::Keyword("currentColor".to_string()).to_string(),
+            "unresolvable color keyword \"currentColor\""
+        );
+        assert_eq!(
+            ParseColorError::InvalidListItem {
+                index: 3,
+                error: Box::new(ParseColorError::ChannelOutOfRange),
+            }
+            .to_string(),
+            "invalid color at index 3: color channel out of range"
+        );
+    }
+}
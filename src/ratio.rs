@@ -1,8 +1,11 @@
-use std::fmt;
-use std::ops;
+use core::fmt;
+use core::ops;
 
-/// Construct an ratio from percentages. Values outside of the 0-100% range
-/// will cause a panic.
+use crate::math::round;
+
+/// Construct an ratio from percentages. Values above 100% are clamped to 100%, in every
+/// build; see [`Ratio::from_percentage`] to catch them in debug builds instead.
+/// Usable in `const` contexts.
 ///
 /// # Example
 /// ```
@@ -11,25 +14,51 @@ use std::ops;
 /// assert_eq!(percent(0).to_string(), "0%");
 /// assert_eq!(percent(25).to_string(), "25%");
 /// assert_eq!(percent(100).to_string(), "100%");
+/// assert_eq!(percent(150).to_string(), "100%");
 /// ```
-pub fn percent(percentage: u8) -> Ratio {
-    Ratio::from_percentage(percentage)
+pub const fn percent(percentage: u8) -> Ratio {
+    Ratio::from_percentage(if percentage > 100 { 100 } else { percentage })
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 /// A struct that represents a ratio and determines the legal value(s) for a given type.
 /// Clamps any values that fall beyond the valid legal range for the type.
 /// Used to convert a type into a valid percentage representation.
+///
+/// Arithmetic on ratios saturates: results above 100% become 100%, and results
+/// below 0% become 0%. The default ratio is 0%.
+///
+/// # Example
+/// ```
+/// use farver::percent;
+///
+/// assert_eq!(percent(80) + percent(30), percent(100));
+/// assert_eq!(percent(20) - percent(30), percent(0));
+/// assert_eq!(percent(40) * 1.5, percent(60));
+/// assert_eq!(percent(40) * 3.0, percent(100));
+/// ```
 pub struct Ratio(u8);
 
 impl Ratio {
-    pub fn from_percentage(percentage: u8) -> Self {
-        assert!(percentage <= 100, "Invalid value for percentage");
+    /// The smallest ratio, 0%.
+    pub const ZERO: Ratio = Ratio(0);
+
+    /// The largest ratio, 100%.
+    pub const FULL: Ratio = Ratio(u8::MAX);
+
+    /// Converts a whole percentage into a ratio. Percentages above 100% are a mistake
+    /// which fails a debug assertion, and are clamped to 100% in release builds, so that
+    /// no ratio is ever out of range.
+    pub const fn from_percentage(percentage: u8) -> Self {
+        debug_assert!(percentage <= 100, "Invalid value for percentage");
+
+        let percentage = if percentage > 100 { 100 } else { percentage };
 
-        Ratio::from_f32(percentage as f32 / 100.0)
+        // Rounds `percentage * 2.55` half up, in integers so that it works in `const` contexts.
+        Ratio(((percentage as u16 * 255 + 50) / 100) as u8)
     }
 
-    pub fn from_u8(value: u8) -> Self {
+    pub const fn from_u8(value: u8) -> Self {
         Ratio(value)
     }
 
@@ -37,11 +66,53 @@ impl Ratio {
         assert!(float >= 0.0, "Invalid ratio for type f32");
         assert!(float <= 1.0, "Invalid ratio for type f32");
 
-        Ratio((float * 255.0).round() as u8)
+        Ratio(round(float * 255.0) as u8)
     }
 
+    /// Restricts `self` to the range between `min` and `max`, inclusive.
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{percent, Ratio};
+    ///
+    /// assert_eq!(percent(80).clamp(percent(20), percent(60)), percent(60));
+    /// assert_eq!(percent(10).clamp(percent(20), Ratio::FULL), percent(20));
+    /// ```
+    pub const fn clamp(self, min: Ratio, max: Ratio) -> Ratio {
+        assert!(min.0 <= max.0, "Invalid bounds for ratio");
+
+        if self.0 < min.0 {
+            min
+        } else if self.0 > max.0 {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Linearly interpolates between `self` and `other`, so a `t` of 0% gives `self`,
+    /// 100% gives `other`, and anything in between the matching point on the way.
+    /// This is how every channel moves in [`Color::lerp`](crate::Color::lerp).
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{percent, Ratio};
+    ///
+    /// assert_eq!(percent(20).lerp(percent(60), percent(0)), percent(20));
+    /// assert_eq!(percent(20).lerp(percent(60), percent(50)), percent(40));
+    /// assert_eq!(percent(60).lerp(percent(20), Ratio::FULL), percent(20));
+    /// ```
+    pub fn lerp(self, other: Ratio, t: Ratio) -> Ratio {
+        let t = t.as_f32();
+
+        Ratio::from_f32((self.as_f32() * (1.0 - t) + other.as_f32() * t).clamp(0.0, 1.0))
+    }
+
+    /// Returns `self` as a whole percentage, rounding halves up. The rounding is
+    /// done with integer arithmetic, so the result is the same on every platform.
     pub fn as_percentage(self) -> u8 {
-        (self.0 as f32 / 255.0 * 100.0).round() as u8
+        ((self.0 as u32 * 200 + 255) / 510) as u8
     }
 
     pub fn as_u8(self) -> u8 {
@@ -53,6 +124,24 @@ impl Ratio {
     }
 }
 
+/// Converts a fraction within `0.0-1.0` into a ratio, so `0.5` is 50%. The float is
+/// not a percentage: `50.0` is clamped to 100%, as are all values above `1.0`, while
+/// negative values and `NaN` become 0%.
+///
+/// # Example
+/// ```
+/// use farver::{percent, Ratio};
+///
+/// assert_eq!(Ratio::from(0.25), percent(25));
+/// assert_eq!(Ratio::from(50.0), percent(100));
+/// assert_eq!(Ratio::from(-0.5), percent(0));
+/// ```
+impl From<f32> for Ratio {
+    fn from(fraction: f32) -> Self {
+        clamp_ratio(fraction)
+    }
+}
+
 impl fmt::Display for Ratio {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}%", self.as_percentage())
@@ -83,6 +172,16 @@ impl ops::Mul for Ratio {
     }
 }
 
+/// Scales a ratio by a factor, saturating at 0% and 100%. Negative and `NaN`
+/// factors give 0%.
+impl ops::Mul<f32> for Ratio {
+    type Output = Ratio;
+
+    fn mul(self, factor: f32) -> Ratio {
+        clamp_ratio(self.as_f32() * factor)
+    }
+}
+
 impl ops::Div for Ratio {
     type Output = Ratio;
 
@@ -107,17 +206,127 @@ mod tests {
     use super::Ratio;
 
     #[test]
+    #[cfg(debug_assertions)]
     #[should_panic]
     fn handles_invalid_percentage() {
         Ratio::from_percentage(101);
     }
 
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn clamps_invalid_percentage_in_release_builds() {
+        assert_eq!(Ratio::from_percentage(101), Ratio::FULL);
+        assert_eq!(Ratio::from_percentage(200), Ratio::FULL);
+        assert_eq!(Ratio::from_percentage(u8::MAX), Ratio::FULL);
+        assert_eq!(crate::hsl(6, 200, 50), crate::hsl(6, 100, 50));
+    }
+
+    #[test]
+    fn clamps_percent_above_full() {
+        assert_eq!(super::percent(101), super::percent(100));
+        assert_eq!(super::percent(u8::MAX).as_u8(), 255);
+    }
+
+    #[test]
+    fn can_lerp() {
+        let (start, half, end) = (super::percent(0), super::percent(50), Ratio::FULL);
+
+        assert_eq!(Ratio::ZERO.lerp(Ratio::FULL, start), Ratio::ZERO);
+        assert_eq!(Ratio::ZERO.lerp(Ratio::FULL, half), Ratio::from_u8(128));
+        assert_eq!(Ratio::ZERO.lerp(Ratio::FULL, end), Ratio::FULL);
+        assert_eq!(Ratio::FULL.lerp(Ratio::ZERO, half), Ratio::from_u8(127));
+        assert_eq!(
+            Ratio::from_u8(100).lerp(Ratio::from_u8(200), half),
+            Ratio::from_u8(150)
+        );
+        assert_eq!(
+            Ratio::from_u8(77).lerp(Ratio::from_u8(77), half),
+            Ratio::from_u8(77)
+        );
+    }
+
+    #[test]
+    fn builds_percentages_in_const_contexts() {
+        const HALF: Ratio = super::percent(50);
+        const OVER: Ratio = super::percent(150);
+
+        assert_eq!(HALF, Ratio::from_u8(128));
+        assert_eq!(OVER, Ratio::FULL);
+
+        for percentage in 0..=100 {
+            assert_eq!(
+                Ratio::from_percentage(percentage),
+                Ratio::from_f32(percentage as f32 / 100.0)
+            );
+        }
+    }
+
     #[test]
     #[should_panic]
     fn handles_invalid_f32() {
         Ratio::from_f32(1.01);
     }
 
+    #[test]
+    fn exposes_bounds() {
+        assert_eq!(Ratio::ZERO, super::percent(0));
+        assert_eq!(Ratio::FULL, super::percent(100));
+        assert_eq!(Ratio::FULL - Ratio::ZERO, Ratio::FULL);
+    }
+
+    #[test]
+    fn clamps_between_bounds() {
+        let (min, max) = (super::percent(20), super::percent(60));
+
+        assert_eq!(super::percent(10).clamp(min, max), min);
+        assert_eq!(super::percent(40).clamp(min, max), super::percent(40));
+        assert_eq!(super::percent(90).clamp(min, max), max);
+        assert_eq!(Ratio::FULL.clamp(min, min), min);
+        assert_eq!(
+            super::percent(40).clamp(Ratio::ZERO, Ratio::FULL),
+            super::percent(40)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn handles_inverted_clamp_bounds() {
+        super::percent(40).clamp(super::percent(60), super::percent(20));
+    }
+
+    #[test]
+    fn maps_bounds_exactly() {
+        assert_eq!(Ratio::from_percentage(0).as_u8(), 0);
+        assert_eq!(Ratio::from_percentage(100).as_u8(), 255);
+        assert_eq!(Ratio::from_f32(0.0).as_u8(), 0);
+        assert_eq!(Ratio::from_f32(1.0).as_u8(), 255);
+        assert_eq!(Ratio::from_u8(0).as_f32(), 0.0);
+        assert_eq!(Ratio::from_u8(255).as_f32(), 1.0);
+        assert_eq!(Ratio::from_u8(255).as_percentage(), 100);
+    }
+
+    #[test]
+    fn rounds_percentages_half_up() {
+        // 14/255 is 5.49% and 37/255 is 14.51%, the closest a ratio gets to
+        // a half percent from either side.
+        assert_eq!(Ratio::from_u8(14).as_percentage(), 5);
+        assert_eq!(Ratio::from_u8(37).as_percentage(), 15);
+        assert_eq!(Ratio::from_u8(1).as_percentage(), 0);
+        assert_eq!(Ratio::from_u8(2).as_percentage(), 1);
+        assert_eq!(Ratio::from_u8(254).as_percentage(), 100);
+        assert_eq!(Ratio::from_u8(237).to_string(), "93%");
+    }
+
+    #[test]
+    fn round_trips_every_percentage() {
+        for percentage in 0..=100 {
+            assert_eq!(
+                Ratio::from_percentage(percentage).as_percentage(),
+                percentage
+            );
+        }
+    }
+
     #[test]
     fn can_clamp_percentage() {
         assert_eq!(
@@ -134,6 +343,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn converts_fractions() {
+        assert_eq!(Ratio::from(0.0), Ratio::from_u8(0));
+        assert_eq!(Ratio::from(1.0), Ratio::from_u8(255));
+        assert_eq!(Ratio::from(0.5), Ratio::from_f32(0.5));
+        assert_eq!(Ratio::from(1.01), Ratio::from_u8(255));
+        assert_eq!(Ratio::from(f32::NAN), Ratio::from_u8(0));
+    }
+
     #[test]
     fn can_clamp_f32() {
         assert_eq!(
@@ -150,6 +368,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn saturates_at_bounds() {
+        let full = Ratio::from_u8(255);
+        let empty = Ratio::from_u8(0);
+
+        assert_eq!(full + Ratio::from_u8(1), full);
+        assert_eq!(full + full, full);
+        assert_eq!(empty - Ratio::from_u8(1), empty);
+        assert_eq!(Ratio::from_u8(254) + Ratio::from_u8(1), full);
+        assert_eq!(Ratio::from_u8(1) - Ratio::from_u8(1), empty);
+        assert_eq!(
+            Ratio::from_u8(100) + Ratio::from_u8(55),
+            Ratio::from_u8(155)
+        );
+        assert_eq!(Ratio::from_u8(100) - Ratio::from_u8(55), Ratio::from_u8(45));
+    }
+
+    #[test]
+    fn scales_by_f32() {
+        assert_eq!(Ratio::from_u8(100) * 0.5, Ratio::from_u8(50));
+        assert_eq!(Ratio::from_u8(100) * 1.0, Ratio::from_u8(100));
+        assert_eq!(Ratio::from_u8(100) * 0.0, Ratio::from_u8(0));
+        assert_eq!(Ratio::from_u8(200) * 2.0, Ratio::from_u8(255));
+        assert_eq!(Ratio::from_u8(200) * f32::INFINITY, Ratio::from_u8(255));
+        assert_eq!(Ratio::from_u8(200) * -1.0, Ratio::from_u8(0));
+        assert_eq!(Ratio::from_u8(200) * f32::NAN, Ratio::from_u8(0));
+    }
+
     #[test]
     fn adds_percentage() {
         let a = Ratio::from_percentage(55);
@@ -1,5 +1,10 @@
-use super::{deg, percent, Angle, Color, Ratio, HSL, HSLA};
-use std::fmt;
+use super::{percent, Angle, Color, Ratio, HSL, HSLA, HSV};
+use crate::fine::{FineAngle, FineRatio};
+use crate::lab::{from_linear, to_linear, Lab, Oklab};
+use crate::math::powf;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
 
 /// Constructs a RGB Color from numerical values, similar to the
 /// [`rgb` function](css-rgb) in CSS.
@@ -14,7 +19,7 @@ use std::fmt;
 /// ```
 ///
 /// [css-rgb]: https://www.w3.org/TR/css-color-3/#rgb-color
-pub fn rgb(r: u8, g: u8, b: u8) -> RGB {
+pub const fn rgb(r: u8, g: u8, b: u8) -> RGB {
     RGB {
         r: Ratio::from_u8(r),
         g: Ratio::from_u8(g),
@@ -26,7 +31,8 @@ pub fn rgb(r: u8, g: u8, b: u8) -> RGB {
 /// [`rgba` function](css-rgba) in CSS.
 ///
 /// The alpha value is expressed as a float. Values outside of the
-/// 0.0-1.0 range will cause a panic.
+/// 0.0-1.0 range are clamped into it, and `NaN` gives a fully
+/// transparent color.
 ///
 /// # Example
 /// ```
@@ -35,6 +41,7 @@ pub fn rgb(r: u8, g: u8, b: u8) -> RGB {
 /// let salmon = rgba(250, 128, 114, 0.50);
 ///
 /// assert_eq!(salmon.to_css(), "rgba(250, 128, 114, 0.50)");
+/// assert_eq!(rgba(250, 128, 114, -1.0).to_css(), "rgba(250, 128, 114, 0.00)");
 /// ```
 ///
 /// [css-rgba]: https://www.w3.org/TR/css-color-3/#rgba-color
@@ -43,25 +50,440 @@ pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> RGBA {
         r: Ratio::from_u8(r),
         g: Ratio::from_u8(g),
         b: Ratio::from_u8(b),
-        a: Ratio::from_f32(a),
+        a: Ratio::from(a),
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Computes the weighted average of many colors in the RGB color space,
+/// including their alpha channels. Each color in `colors` is paired with the
+/// weight at the same index in `weights`, and the weights are normalized so
+/// that they don't need to add up to 100%.
+///
+/// Returns `None` if `colors` is empty, if `colors` and `weights` differ in
+/// length, or if every weight is zero.
+///
+/// # Example
+/// ```
+/// use farver::{mix_all, percent, rgba};
+///
+/// let red = rgba(255, 0, 0, 1.0);
+/// let blue = rgba(0, 0, 255, 0.0);
+///
+/// assert_eq!(
+///     mix_all(&[red, blue], &[percent(75), percent(25)]),
+///     Some(rgba(191, 0, 64, 0.75))
+/// );
+/// assert_eq!(mix_all(&[red, blue], &[percent(100)]), None);
+/// ```
+pub fn mix_all(colors: &[RGBA], weights: &[Ratio]) -> Option<RGBA> {
+    if colors.is_empty() || colors.len() != weights.len() {
+        return None;
+    }
+
+    let total: f32 = weights.iter().map(|w| w.as_f32()).sum();
+
+    if total == 0.0 {
+        return None;
+    }
+
+    let mut channels = [0.0; 4];
+
+    for (color, weight) in colors.iter().zip(weights) {
+        let weight = weight.as_f32() / total;
+
+        channels[0] += color.r.as_f32() * weight;
+        channels[1] += color.g.as_f32() * weight;
+        channels[2] += color.b.as_f32() * weight;
+        channels[3] += color.a.as_f32() * weight;
+    }
+
+    let [r, g, b, a] = channels.map(|c| Ratio::from_f32(c.clamp(0.0, 1.0)));
+
+    Some(RGBA { r, g, b, a })
+}
+
+/// Computes the average of `colors` in the RGB color space, averaging each channel
+/// of the gamma encoded sRGB values. Returns `None` if `colors` is empty.
+///
+/// This is how most tools average colors, but since sRGB values aren't proportional
+/// to the light they stand for, averaging bright and dark colors this way gives a
+/// darker result than blending their light would. For the color a group of pixels
+/// looks like from afar, such as when downscaling an image, use [`average_linear`].
+///
+/// # Example
+/// ```
+/// use farver::{average, rgb};
+///
+/// let pixels = [rgb(255, 0, 0), rgb(0, 255, 0)];
+///
+/// assert_eq!(average(pixels), Some(rgb(128, 128, 0)));
+/// assert_eq!(average(Vec::new()), None);
+/// ```
+pub fn average(colors: impl IntoIterator<Item = RGB>) -> Option<RGB> {
+    average_with(colors, |channel| channel, |channel| channel)
+}
+
+/// Computes the average of `colors` in linear light, converting each channel out of
+/// its sRGB gamma encoding before averaging, and back into it afterwards. Returns `None`
+/// if `colors` is empty.
+///
+/// Unlike [`average`], this matches how the light of the colors adds up, so it gives
+/// the color that a pattern of `colors` appears as when seen from afar. It's brighter
+/// than the [`average`] of colors which differ a lot in brightness, and equal to it for
+/// colors which are all alike.
+///
+/// # Example
+/// ```
+/// use farver::{average, average_linear, rgb};
+///
+/// let checkerboard = [rgb(0, 0, 0), rgb(255, 255, 255)];
+///
+/// assert_eq!(average(checkerboard), Some(rgb(128, 128, 128)));
+/// assert_eq!(average_linear(checkerboard), Some(rgb(188, 188, 188)));
+/// ```
+pub fn average_linear(colors: impl IntoIterator<Item = RGB>) -> Option<RGB> {
+    average_with(colors, to_linear, from_linear)
+}
+
+// Averages the channels of `colors` once converted by `encode`, converting the averages
+// back with `decode`. The sums are kept in `f64`, so that averaging the millions of
+// pixels of an image doesn't lose the precision of every channel.
+fn average_with(
+    colors: impl IntoIterator<Item = RGB>,
+    encode: impl Fn(f32) -> f32,
+    decode: impl Fn(f32) -> f32,
+) -> Option<RGB> {
+    let mut sums = [0.0_f64; 3];
+    let mut count = 0_u64;
+
+    for color in colors {
+        for (sum, channel) in sums.iter_mut().zip([color.r, color.g, color.b]) {
+            *sum += f64::from(encode(channel.as_f32()));
+        }
+
+        count += 1;
+    }
+
+    if count == 0 {
+        return None;
+    }
+
+    let [r, g, b] =
+        sums.map(|sum| Ratio::from_f32(decode((sum / count as f64) as f32).clamp(0.0, 1.0)));
+
+    Some(RGB { r, g, b })
+}
+
+// Blends each channel of `lhs` with the same channel of `rhs` using `op`, which
+// receives and returns channels normalized to `0.0-1.0`.
+pub(crate) fn blend(lhs: RGB, rhs: RGB, op: impl Fn(f32, f32) -> f32) -> RGB {
+    let channel = |lhs: Ratio, rhs: Ratio| Ratio::from_f32(op(lhs.as_f32(), rhs.as_f32()));
+
+    RGB {
+        r: channel(lhs.r, rhs.r),
+        g: channel(lhs.g, rhs.g),
+        b: channel(lhs.b, rhs.b),
+    }
+}
+
+/// Converts normalized red, green and blue channels within `0.0-1.0` into a hue in
+/// degrees within `0.0-360.0`, and a saturation and lightness within `0.0-1.0`.
+/// This is the conversion behind [`Color::to_hsl`] for `RGB` colors, before the
+/// channels are rounded into an [`HSL`].
+///
+/// # Example
+/// ```
+/// use farver::rgb_to_hsl;
+///
+/// assert_eq!(rgb_to_hsl(1.0, 0.0, 0.0), (0.0, 1.0, 0.5));
+/// assert_eq!(rgb_to_hsl(0.0, 0.0, 1.0), (240.0, 1.0, 0.5));
+/// assert_eq!(rgb_to_hsl(0.5, 0.5, 0.5), (0.0, 0.0, 0.5));
+/// ```
+pub fn rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let (hue, saturation, luminosity) = rgb_to_signed_hsl(r, g, b);
+    let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+
+    (hue, saturation, luminosity)
+}
+
+// Adds `amount` to the CIE L*a*b* lightness of `color`, keeping its alpha. Colors
+// that leave the sRGB gamut lose chroma rather than lightness.
+fn shift_lab_lightness(color: RGBA, amount: f32) -> RGBA {
+    let lab = Lab::from_rgb(color.to_rgb());
+
+    let RGB { r, g, b } = Lab {
+        l: (lab.l + amount).clamp(0.0, 100.0),
+        ..lab
+    }
+    .clamp_chroma()
+    .to_rgb();
+
+    RGBA {
+        r,
+        g,
+        b,
+        a: color.a,
+    }
+}
+
+// Multiplies the linear light of each channel of `color` by `factor`, keeping its alpha.
+fn scale_linear(color: RGBA, factor: f32) -> RGBA {
+    let channel = |channel: Ratio| {
+        Ratio::from_f32(from_linear((to_linear(channel.as_f32()) * factor).min(1.0)))
+    };
+
+    RGBA {
+        r: channel(color.r),
+        g: channel(color.g),
+        b: channel(color.b),
+        a: color.a,
+    }
+}
+
+// Moves the Oklab chroma and hue of `color` towards those of `target` by `amount`,
+// keeping its Oklab lightness and alpha.
+fn shift_towards(color: RGBA, target: RGB, amount: Ratio) -> RGBA {
+    let lab = Oklab::from_rgb(color.to_rgb());
+    let target = Oklab::from_rgb(target);
+    let w = amount.as_f32();
+
+    let RGB { r, g, b } = Oklab {
+        l: lab.l,
+        a: lab.a + (target.a - lab.a) * w,
+        b: lab.b + (target.b - lab.b) * w,
+    }
+    .to_rgb();
+
+    RGBA {
+        r,
+        g,
+        b,
+        a: color.a,
+    }
+}
+
+// Like `rgb_to_hsl`, but reds leaning towards blue keep a negative hue within
+// `-60.0-0.0`. Colors round this hue before wrapping it, so that halves round
+// away from the red axis in both directions.
+fn rgb_to_signed_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    // If r, g, and b are the same, the color is a shade of grey (between
+    // black and white), with no hue or saturation. In that situation, there
+    // is no saturation or hue, and we can use any value to determine luminosity.
+    if r == g && g == b {
+        return (0.0, 0.0, r);
+    }
+
+    // Otherwise, to determine luminosity, we find the max and the min of the
+    // channels, sum them together, and divide by 2.
+    let max = if r > g && r > b {
+        r
+    } else if g > b {
+        g
+    } else {
+        b
+    };
+
+    let min = if r < g && r < b {
+        r
+    } else if g < b {
+        g
+    } else {
+        b
+    };
+
+    let luminosity = (max + min) / 2.0;
+
+    // To find the saturation, we look at the max and min values.
+    // If the max and the min are the same, there is no saturation to the color.
+    // Otherwise, we calculate the saturation based on if the luminosity is
+    // greater than or less than 0.5.
+    let saturation = if max == min {
+        0.0
+    } else if luminosity < 0.5 {
+        (max - min) / (max + min)
+    } else {
+        (max - min) / (2.0 - (max + min))
+    };
+
+    // To calculate the hue, we look at which value (r, g, or b) is the max.
+    // Based on that, we subtract the difference between the other two values,
+    // adding 120 or 240 deg to account for the degrees on the color wheel, and
+    // then dividing that by the difference between the max and the min values.
+    // Finally, we multiply the hue value by 60 to convert it to degrees on
+    // the color wheel, accounting for negative hues as well.
+    let hue = if max == r {
+        60.0 * (g - b) / (max - min)
+    } else if max == g {
+        120.0 + 60.0 * (b - r) / (max - min)
+    } else {
+        240.0 + 60.0 * (r - g) / (max - min)
+    };
+
+    // Rounding errors can push the saturation of nearly saturated colors, such
+    // as `rgb(1, 1, 255)`, just past 1.0.
+    (hue, saturation.min(1.0), luminosity)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// One of the red, green and blue channels of a color, as returned by
+/// [`Color::dominant_channel`].
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 /// A struct to represent how much red, green, and blue should be added to create a color.
 ///
 /// Valid values for r, g, and b must be a u8 between `0-255`, represented as a `Ratio`.
+/// The channels can be read with [`RGB::red`], [`RGB::green`] and [`RGB::blue`], and
+/// replaced with [`RGB::with_red`], [`RGB::with_green`] and [`RGB::with_blue`].
 ///
 /// For more, see the [CSS Color Spec](https://www.w3.org/TR/2018/REC-css-color-3-20180619/#rgb-color).
+///
+/// The default `RGB` is black, `rgb(0, 0, 0)`.
 pub struct RGB {
     // red
-    pub r: Ratio,
+    pub(crate) r: Ratio,
 
     // green
-    pub g: Ratio,
+    pub(crate) g: Ratio,
 
     // blue
-    pub b: Ratio,
+    pub(crate) b: Ratio,
+}
+
+impl RGB {
+    /// Unpacks a RGB Color from an integer in the format `0x00RRGGBB`.
+    /// The most significant byte is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, RGB};
+    ///
+    /// assert_eq!(RGB::from_u32(0xfa8072), rgb(250, 128, 114));
+    /// ```
+    pub fn from_u32(value: u32) -> Self {
+        let [_, r, g, b] = value.to_be_bytes();
+
+        rgb(r, g, b)
+    }
+
+    /// Returns the red channel of `self` within `0-255`.
+    pub fn red(self) -> u8 {
+        self.r.as_u8()
+    }
+
+    /// Returns the green channel of `self` within `0-255`.
+    pub fn green(self) -> u8 {
+        self.g.as_u8()
+    }
+
+    /// Returns the blue channel of `self` within `0-255`.
+    pub fn blue(self) -> u8 {
+        self.b.as_u8()
+    }
+
+    /// Returns a copy of `self` with its red channel set to `r`.
+    pub fn with_red(self, r: u8) -> Self {
+        Self {
+            r: Ratio::from_u8(r),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its green channel set to `g`.
+    pub fn with_green(self, g: u8) -> Self {
+        Self {
+            g: Ratio::from_u8(g),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its blue channel set to `b`.
+    pub fn with_blue(self, b: u8) -> Self {
+        Self {
+            b: Ratio::from_u8(b),
+            ..self
+        }
+    }
+
+    /// Returns the red, green and blue channels of `self` within `0-255`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgb, RGB};
+    ///
+    /// assert_eq!(rgb(250, 128, 114).to_array(), [250, 128, 114]);
+    /// assert_eq!(RGB::from([250, 128, 114]), rgb(250, 128, 114));
+    /// ```
+    pub fn to_array(self) -> [u8; 3] {
+        [self.r.as_u8(), self.g.as_u8(), self.b.as_u8()]
+    }
+
+    /// Returns the red, green and blue channels of `self` within `0.0-1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgb;
+    ///
+    /// assert_eq!(rgb(255, 0, 51).to_f32_array(), [1.0, 0.0, 0.2]);
+    /// ```
+    pub fn to_f32_array(self) -> [f32; 3] {
+        [self.r.as_f32(), self.g.as_f32(), self.b.as_f32()]
+    }
+
+    /// Converts `self` to its CSS string format, with every channel written as a whole
+    /// percentage rather than within `0-255`. Channels are rounded to the nearest percentage,
+    /// with halves rounded up, so `128` becomes `50%`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgb;
+    ///
+    /// assert_eq!(rgb(255, 128, 0).to_css_percent(), "rgb(100%, 50%, 0%)");
+    /// ```
+    pub fn to_css_percent(self) -> String {
+        format!("rgb({}, {}, {})", self.r, self.g, self.b)
+    }
+
+    /// Cycles the channels of `self`, so the red value moves to the green channel,
+    /// green to blue, and blue to red.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgb;
+    ///
+    /// assert_eq!(rgb(250, 128, 114).rotate_channels(), rgb(114, 250, 128));
+    /// assert_eq!(rgb(250, 128, 114).swap_rg(), rgb(128, 250, 114));
+    /// assert_eq!(rgb(250, 128, 114).swap_gb(), rgb(250, 114, 128));
+    /// ```
+    pub fn rotate_channels(self) -> Self {
+        Self {
+            r: self.b,
+            g: self.r,
+            b: self.g,
+        }
+    }
+
+    /// Swaps the red and green channels of `self`.
+    pub fn swap_rg(self) -> Self {
+        Self {
+            r: self.g,
+            g: self.r,
+            ..self
+        }
+    }
+
+    /// Swaps the green and blue channels of `self`.
+    pub fn swap_gb(self) -> Self {
+        Self {
+            g: self.b,
+            b: self.g,
+            ..self
+        }
+    }
 }
 
 impl fmt::Display for RGB {
@@ -79,12 +501,26 @@ impl fmt::Display for RGB {
 impl Color for RGB {
     type Alpha = RGBA;
 
-    fn to_css(self) -> String {
-        self.to_string()
+    fn write_css(self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{}", self)
+    }
+
+    fn to_css_minimal(self) -> String {
+        self.to_css()
     }
 
-    fn to_hex(self) -> String {
+    fn to_css_modern(self) -> String {
         format!(
+            "rgb({} {} {})",
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8()
+        )
+    }
+
+    fn write_hex(self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(
+            w,
             "#{:02x}{:02x}{:02x}",
             self.r.as_u8(),
             self.g.as_u8(),
@@ -92,6 +528,19 @@ impl Color for RGB {
         )
     }
 
+    fn to_hex_bare(self) -> String {
+        format!(
+            "{:02x}{:02x}{:02x}",
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8()
+        )
+    }
+
+    fn to_u32(self) -> u32 {
+        u32::from_be_bytes([0, self.r.as_u8(), self.g.as_u8(), self.b.as_u8()])
+    }
+
     fn to_rgb(self) -> RGB {
         self
     }
@@ -117,6 +566,10 @@ impl Color for RGB {
         self.to_rgba().to_hsla()
     }
 
+    fn to_hsv(self) -> HSV {
+        self.to_rgba().to_hsv()
+    }
+
     fn saturate(self, amount: Ratio) -> Self {
         self.to_rgba().saturate(amount).to_rgb()
     }
@@ -133,6 +586,46 @@ impl Color for RGB {
         self.to_rgba().darken(amount).to_rgb()
     }
 
+    fn saturate_relative(self, amount: Ratio) -> Self {
+        self.to_rgba().saturate_relative(amount).to_rgb()
+    }
+
+    fn desaturate_relative(self, amount: Ratio) -> Self {
+        self.to_rgba().desaturate_relative(amount).to_rgb()
+    }
+
+    fn lighten_relative(self, amount: Ratio) -> Self {
+        self.to_rgba().lighten_relative(amount).to_rgb()
+    }
+
+    fn darken_relative(self, amount: Ratio) -> Self {
+        self.to_rgba().darken_relative(amount).to_rgb()
+    }
+
+    fn lighten_lab(self, amount: Ratio) -> Self {
+        self.to_rgba().lighten_lab(amount).to_rgb()
+    }
+
+    fn darken_lab(self, amount: Ratio) -> Self {
+        self.to_rgba().darken_lab(amount).to_rgb()
+    }
+
+    fn brighten(self, amount: Ratio) -> Self {
+        self.to_rgba().brighten(amount).to_rgb()
+    }
+
+    fn dim(self, amount: Ratio) -> Self {
+        self.to_rgba().dim(amount).to_rgb()
+    }
+
+    fn scale_saturation(self, amount: f32) -> Self {
+        self.to_rgba().scale_saturation(amount).to_rgb()
+    }
+
+    fn scale_lightness(self, amount: f32) -> Self {
+        self.to_rgba().scale_lightness(amount).to_rgb()
+    }
+
     fn fadein(self, amount: Ratio) -> RGBA {
         self.to_rgba().fadein(amount)
     }
@@ -141,23 +634,45 @@ impl Color for RGB {
         self.to_rgba().fadeout(amount)
     }
 
-    fn fade(self, amount: Ratio) -> RGBA {
+    fn fade(self, amount: impl Into<Ratio>) -> RGBA {
         self.to_rgba().fade(amount)
     }
 
+    fn stack_alpha(self, times: u32) -> RGBA {
+        self.to_rgba().stack_alpha(times)
+    }
+
     fn spin(self, amount: Angle) -> Self {
         self.to_rgba().spin(amount).to_rgb()
     }
 
-    fn mix<T: Color>(self, other: T, weight: Ratio) -> RGBA {
+    fn accent(self) -> Self {
+        self.to_rgba().accent().to_rgb()
+    }
+
+    fn ensure_contrast<T: Color>(self, background: T, min_ratio: f32) -> Self {
+        self.to_rgba()
+            .ensure_contrast(background, min_ratio)
+            .to_rgb()
+    }
+
+    fn mix<T: Color>(self, other: T, weight: impl Into<Ratio>) -> RGBA {
         self.to_rgba().mix(other, weight)
     }
 
-    fn tint(self, weight: Ratio) -> Self {
+    fn mix_opaque<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self {
+        self.to_rgba().mix_opaque(other, weight).to_rgb()
+    }
+
+    fn lerp<T: Color>(self, other: T, t: impl Into<Ratio>) -> RGBA {
+        self.to_rgba().lerp(other, t)
+    }
+
+    fn tint(self, weight: impl Into<Ratio>) -> Self {
         self.to_rgba().tint(weight).to_rgb()
     }
 
-    fn shade(self, weight: Ratio) -> Self {
+    fn shade(self, weight: impl Into<Ratio>) -> Self {
         self.to_rgba().shade(weight).to_rgb()
     }
 
@@ -165,6 +680,26 @@ impl Color for RGB {
         self.to_rgba().greyscale().to_rgb()
     }
 
+    fn invert_lightness(self) -> Self {
+        self.to_rgba().invert_lightness().to_rgb()
+    }
+
+    fn is_grayscale(self) -> bool {
+        self.to_rgba().is_grayscale()
+    }
+
+    fn map_rgb(self, f: impl Fn(Ratio) -> Ratio) -> Self {
+        self.to_rgba().map_rgb(f).to_rgb()
+    }
+
+    fn warm(self, amount: Ratio) -> Self {
+        self.to_rgba().warm(amount).to_rgb()
+    }
+
+    fn cool(self, amount: Ratio) -> Self {
+        self.to_rgba().cool(amount).to_rgb()
+    }
+
     #[cfg(feature = "ansi_term")]
     fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
         &self,
@@ -185,26 +720,253 @@ impl From<RGB> for (u8, u8, u8) {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+impl From<[u8; 3]> for RGB {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        rgb(r, g, b)
+    }
+}
+
+impl From<(u8, u8, u8)> for RGB {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        rgb(r, g, b)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 /// A struct to represent how much red, green, and blue should be added to create a color.
 /// Also handles alpha specifications.
 ///
 /// Valid values for r, g, and b must be a u8 between `0-255`, represented as a `Ratio`.
 /// Alpha (a) values must fall between `0-255`.
+/// The channels can be read with [`RGBA::red`], [`RGBA::green`], [`RGBA::blue`] and
+/// [`RGBA::alpha_f32`], and replaced with [`RGBA::with_red`], [`RGBA::with_green`],
+/// [`RGBA::with_blue`] and [`Color::fade`].
 ///
 /// For more, see the [CSS Color Spec](https://www.w3.org/TR/2018/REC-css-color-3-20180619/#rgba-color).
+///
+/// The default `RGBA` is transparent black, `rgba(0, 0, 0, 0.0)`, like the CSS `transparent`
+/// keyword, so that a color left unset doesn't cover anything up.
 pub struct RGBA {
     // red
-    pub r: Ratio,
+    pub(crate) r: Ratio,
 
     // green
-    pub g: Ratio,
+    pub(crate) g: Ratio,
 
     // blue
-    pub b: Ratio,
+    pub(crate) b: Ratio,
 
     // alpha
-    pub a: Ratio,
+    pub(crate) a: Ratio,
+}
+
+impl RGBA {
+    /// Unpacks a RGBA Color from an integer in the format `0xRRGGBBAA`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, RGBA};
+    ///
+    /// assert_eq!(RGBA::from_u32(0xfa8072ff), rgba(250, 128, 114, 1.0));
+    /// ```
+    pub fn from_u32(value: u32) -> Self {
+        let [r, g, b, a] = value.to_be_bytes();
+
+        RGBA {
+            r: Ratio::from_u8(r),
+            g: Ratio::from_u8(g),
+            b: Ratio::from_u8(b),
+            a: Ratio::from_u8(a),
+        }
+    }
+
+    /// Returns the red channel of `self` within `0-255`.
+    pub fn red(self) -> u8 {
+        self.r.as_u8()
+    }
+
+    /// Returns the green channel of `self` within `0-255`.
+    pub fn green(self) -> u8 {
+        self.g.as_u8()
+    }
+
+    /// Returns the blue channel of `self` within `0-255`.
+    pub fn blue(self) -> u8 {
+        self.b.as_u8()
+    }
+
+    /// Returns a copy of `self` with its red channel set to `r`.
+    pub fn with_red(self, r: u8) -> Self {
+        Self {
+            r: Ratio::from_u8(r),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its green channel set to `g`.
+    pub fn with_green(self, g: u8) -> Self {
+        Self {
+            g: Ratio::from_u8(g),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its blue channel set to `b`.
+    pub fn with_blue(self, b: u8) -> Self {
+        Self {
+            b: Ratio::from_u8(b),
+            ..self
+        }
+    }
+
+    /// Returns the alpha channel of `self` within `0.0-1.0`.
+    pub fn alpha_f32(self) -> f32 {
+        self.a.as_f32()
+    }
+
+    /// Returns the red, green, blue and alpha channels of `self` within `0-255`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::{rgba, RGBA};
+    ///
+    /// assert_eq!(rgba(250, 128, 114, 1.0).to_array(), [250, 128, 114, 255]);
+    /// assert_eq!(RGBA::from([250, 128, 114, 255]), rgba(250, 128, 114, 1.0));
+    /// ```
+    pub fn to_array(self) -> [u8; 4] {
+        [
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8(),
+            self.a.as_u8(),
+        ]
+    }
+
+    /// Returns the red, green, blue and alpha channels of `self` within `0.0-1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgba;
+    ///
+    /// assert_eq!(rgba(255, 0, 51, 1.0).to_f32_array(), [1.0, 0.0, 0.2, 1.0]);
+    /// ```
+    pub fn to_f32_array(self) -> [f32; 4] {
+        [
+            self.r.as_f32(),
+            self.g.as_f32(),
+            self.b.as_f32(),
+            self.a.as_f32(),
+        ]
+    }
+
+    /// Converts `self` to its CSS string format, with every color channel written as a whole
+    /// percentage rather than within `0-255`, like [`RGB::to_css_percent`]. The alpha channel
+    /// is written as a float, just like [`Color::to_css`] does.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgba;
+    ///
+    /// assert_eq!(rgba(255, 128, 0, 0.5).to_css_percent(), "rgba(100%, 50%, 0%, 0.50)");
+    /// ```
+    pub fn to_css_percent(self) -> String {
+        format!(
+            "rgba({}, {}, {}, {:.02})",
+            self.r,
+            self.g,
+            self.b,
+            self.a.as_f32()
+        )
+    }
+
+    /// Cycles the channels of `self`, so the red value moves to the green channel,
+    /// green to blue, and blue to red.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgba;
+    ///
+    /// assert_eq!(rgba(250, 128, 114, 0.5).rotate_channels(), rgba(114, 250, 128, 0.5));
+    /// assert_eq!(rgba(250, 128, 114, 0.5).swap_rg(), rgba(128, 250, 114, 0.5));
+    /// assert_eq!(rgba(250, 128, 114, 0.5).swap_gb(), rgba(250, 114, 128, 0.5));
+    /// ```
+    pub fn rotate_channels(self) -> Self {
+        Self {
+            r: self.b,
+            g: self.r,
+            b: self.g,
+            ..self
+        }
+    }
+
+    /// Swaps the red and green channels of `self`.
+    pub fn swap_rg(self) -> Self {
+        Self {
+            r: self.g,
+            g: self.r,
+            ..self
+        }
+    }
+
+    /// Swaps the green and blue channels of `self`.
+    pub fn swap_gb(self) -> Self {
+        Self {
+            g: self.b,
+            b: self.g,
+            ..self
+        }
+    }
+
+    /// Converts `self` into premultiplied alpha, multiplying each of the red, green
+    /// and blue channels by the alpha channel. The alpha channel itself is unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgba;
+    ///
+    /// assert_eq!(rgba(250, 128, 114, 0.5).premultiplied(), rgba(125, 64, 57, 0.5));
+    /// ```
+    pub fn premultiplied(self) -> Self {
+        let RGBA { r, g, b, a } = self;
+
+        RGBA {
+            r: r * a,
+            g: g * a,
+            b: b * a,
+            a,
+        }
+    }
+
+    /// Converts `self` from premultiplied alpha back into straight alpha, dividing each
+    /// of the red, green and blue channels by the alpha channel.
+    /// Fully transparent colors carry no color information, so they become transparent black.
+    ///
+    /// # Example
+    /// ```
+    /// use farver::rgba;
+    ///
+    /// assert_eq!(rgba(64, 32, 16, 0.5).unpremultiplied(), rgba(128, 64, 32, 0.5));
+    /// assert_eq!(rgba(64, 32, 16, 0.0).unpremultiplied(), rgba(0, 0, 0, 0.0));
+    /// ```
+    pub fn unpremultiplied(self) -> Self {
+        let RGBA { r, g, b, a } = self;
+
+        if a.as_u8() == 0 {
+            return RGBA {
+                r: Ratio::ZERO,
+                g: Ratio::ZERO,
+                b: Ratio::ZERO,
+                a: Ratio::ZERO,
+            };
+        }
+
+        RGBA {
+            r: r / a,
+            g: g / a,
+            b: b / a,
+            a,
+        }
+    }
 }
 
 impl fmt::Display for RGBA {
@@ -223,12 +985,33 @@ impl fmt::Display for RGBA {
 impl Color for RGBA {
     type Alpha = Self;
 
-    fn to_css(self) -> String {
-        self.to_string()
+    fn write_css(self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{}", self)
     }
 
-    fn to_hex(self) -> String {
+    fn to_css_minimal(self) -> String {
+        if self.to_u32() == 0 {
+            String::from("transparent")
+        } else if self.a.as_u8() == u8::MAX {
+            self.to_rgb().to_css()
+        } else {
+            self.to_css()
+        }
+    }
+
+    fn to_css_modern(self) -> String {
         format!(
+            "rgb({} {} {} / {})",
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8(),
+            self.a
+        )
+    }
+
+    fn write_hex(self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(
+            w,
             "#{:02x}{:02x}{:02x}{:02x}",
             self.r.as_u8(),
             self.g.as_u8(),
@@ -237,6 +1020,25 @@ impl Color for RGBA {
         )
     }
 
+    fn to_hex_bare(self) -> String {
+        format!(
+            "{:02x}{:02x}{:02x}{:02x}",
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8(),
+            self.a.as_u8()
+        )
+    }
+
+    fn to_u32(self) -> u32 {
+        u32::from_be_bytes([
+            self.r.as_u8(),
+            self.g.as_u8(),
+            self.b.as_u8(),
+            self.a.as_u8(),
+        ])
+    }
+
     fn to_rgb(self) -> RGB {
         let RGBA { r, g, b, .. } = self;
         RGB { r, g, b }
@@ -251,76 +1053,30 @@ impl Color for RGBA {
     }
 
     fn to_hsla(self) -> HSLA {
-        let RGBA { r, g, b, a } = self;
+        let (h, s, l) = rgb_to_signed_hsl(self.r.as_f32(), self.g.as_f32(), self.b.as_f32());
 
-        // If r, g, and b are the same, the color is a shade of grey (between
-        // black and white), with no hue or saturation. In that situation, there
-        // is no saturation or hue, and we can use any value to determine luminosity.
-        if r == g && g == b {
-            return HSLA {
-                h: deg(0),
-                s: percent(0),
-                l: r,
-                a,
-            };
+        HSLA {
+            h: FineAngle::from_f32(h),
+            s: FineRatio::from_f32(s),
+            l: FineRatio::from_f32(l),
+            a: self.a,
         }
+    }
 
-        // Otherwise, to determine luminosity, we convert the RGB values into a
-        // percentage value, find the max and the min of those values, sum them
-        // together, and divide by 2.
-        let r = self.r.as_f32();
-        let g = self.g.as_f32();
-        let b = self.b.as_f32();
-
-        let max = if r > g && r > b {
-            r
-        } else if g > b {
-            g
-        } else {
-            b
-        };
-
-        let min = if r < g && r < b {
-            r
-        } else if g < b {
-            g
-        } else {
-            b
-        };
-
-        let luminosity = (max + min) / 2.0;
+    fn to_hsv(self) -> HSV {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
 
-        // To find the saturation, we look at the max and min values.
-        // If the max and the min are the same, there is no saturation to the color.
-        // Otherwise, we calculate the saturation based on if the luminosity is
-        // greater than or less than 0.5.
-        let saturation = if max == min {
+        let s = if max == percent(0) {
             0.0
-        } else if luminosity < 0.5 {
-            (max - min) / (max + min)
         } else {
-            (max - min) / (2.0 - (max + min))
+            (max - min).as_f32() / max.as_f32()
         };
 
-        // To calculate the hue, we look at which value (r, g, or b) is the max.
-        // Based on that, we subtract the difference between the other two values,
-        // adding 120 or 240 deg to account for the degrees on the color wheel, and
-        // then dividing that by the difference between the max and the min values.
-        // Finally, we multiply the hue value by 60 to convert it to degrees on
-        // the color wheel, accounting for negative hues as well.
-        let hue = if max == r {
-            60.0 * (g - b) / (max - min)
-        } else if max == g {
-            120.0 + 60.0 * (b - r) / (max - min)
-        } else {
-            240.0 + 60.0 * (r - g) / (max - min)
-        };
-
-        HSLA {
-            h: deg(hue.round() as i32),
-            s: Ratio::from_f32(saturation),
-            l: Ratio::from_f32(luminosity),
-            a,
+        HSV {
+            h: self.to_hsla().h.to_angle(),
+            s: Ratio::from_f32(s),
+            v: max,
         }
     }
 
@@ -340,6 +1096,46 @@ impl Color for RGBA {
         self.to_hsla().darken(amount).to_rgba()
     }
 
+    fn saturate_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().saturate_relative(amount).to_rgba()
+    }
+
+    fn desaturate_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().desaturate_relative(amount).to_rgba()
+    }
+
+    fn lighten_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().lighten_relative(amount).to_rgba()
+    }
+
+    fn darken_relative(self, amount: Ratio) -> Self {
+        self.to_hsla().darken_relative(amount).to_rgba()
+    }
+
+    fn lighten_lab(self, amount: Ratio) -> Self {
+        shift_lab_lightness(self, amount.as_f32() * 100.0)
+    }
+
+    fn darken_lab(self, amount: Ratio) -> Self {
+        shift_lab_lightness(self, amount.as_f32() * -100.0)
+    }
+
+    fn brighten(self, amount: Ratio) -> Self {
+        scale_linear(self, 1.0 + amount.as_f32())
+    }
+
+    fn dim(self, amount: Ratio) -> Self {
+        scale_linear(self, 1.0 - amount.as_f32())
+    }
+
+    fn scale_saturation(self, amount: f32) -> Self {
+        self.to_hsla().scale_saturation(amount).to_rgba()
+    }
+
+    fn scale_lightness(self, amount: f32) -> Self {
+        self.to_hsla().scale_lightness(amount).to_rgba()
+    }
+
     fn fadein(self, amount: Ratio) -> Self {
         self.fade(self.a + amount)
     }
@@ -348,20 +1144,49 @@ impl Color for RGBA {
         self.fade(self.a - amount)
     }
 
-    fn fade(self, amount: Ratio) -> Self {
+    fn fade(self, amount: impl Into<Ratio>) -> Self {
         let RGBA { r, g, b, .. } = self;
-        RGBA { r, g, b, a: amount }
+        RGBA {
+            r,
+            g,
+            b,
+            a: amount.into(),
+        }
+    }
+
+    fn stack_alpha(self, times: u32) -> Self {
+        let transparency = 1.0 - self.a.as_f32();
+
+        self.fade(1.0 - powf(transparency, times as f32))
     }
 
     fn spin(self, amount: Angle) -> Self {
         self.to_hsla().spin(amount).to_rgba()
     }
 
+    fn accent(self) -> Self {
+        self.to_hsla().accent().to_rgba()
+    }
+
+    fn ensure_contrast<T: Color>(self, background: T, min_ratio: f32) -> Self {
+        let background = background.to_rgb();
+
+        // Converting to HSL and back can shift the channels slightly, so leave colors that
+        // already have enough contrast alone.
+        if self.contrast_ratio(background) >= min_ratio {
+            self
+        } else {
+            self.to_hsla()
+                .ensure_contrast(background, min_ratio)
+                .to_rgba()
+        }
+    }
+
     // This algorithm takes into account both the user-provided weight (w) and
     // the difference between the alpha values of the two colors (a) to determine
     // the weighted average of the two colors.
     // Taken from Sass's implementation (http://sass-lang.com/documentation/Sass/Script/Functions.html#mix-instance_method)
-    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self {
+    fn mix<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self {
         let RGBA {
             r: r_lhs,
             g: g_lhs,
@@ -376,6 +1201,8 @@ impl Color for RGBA {
             a: a_rhs,
         } = other.to_rgba();
 
+        let weight = weight.into();
+
         // Convert weight into a decimal, and then scale it so that it falls between a range of [-1, 1].
         let w = (weight.as_f32() * 2.0) - 1.0;
 
@@ -392,26 +1219,46 @@ impl Color for RGBA {
         // Find the combined rgb weight, scaling it to fall in a range between [0, 1].
         let rgb_weight = (rgb_weight + 1.0) / 2.0;
 
-        // Convert left and right side's weights into Ratios.
-        let rgb_weight_lhs = Ratio::from_f32(rgb_weight);
-        let rgb_weight_rhs = Ratio::from_f32(1.0) - rgb_weight_lhs;
+        // Blend every channel as a float, so the result is only rounded once.
+        let blend = |lhs: Ratio, rhs: Ratio, weight: f32| {
+            Ratio::from_f32((lhs.as_f32() * weight + rhs.as_f32() * (1.0 - weight)).clamp(0.0, 1.0))
+        };
+
+        let alpha_weight = weight.as_f32();
+
+        RGBA {
+            r: blend(r_lhs, r_rhs, rgb_weight),
+            g: blend(g_lhs, g_rhs, rgb_weight),
+            b: blend(b_lhs, b_rhs, rgb_weight),
+            a: blend(a_lhs, a_rhs, alpha_weight),
+        }
+    }
+
+    fn mix_opaque<T: Color>(self, other: T, weight: impl Into<Ratio>) -> Self {
+        RGBA {
+            a: percent(100),
+            ..self
+        }
+        .mix(other.to_rgb(), weight)
+    }
 
-        let alpha_weight_lhs = weight;
-        let alpha_weight_rhs = Ratio::from_f32(1.0) - alpha_weight_lhs;
+    fn lerp<T: Color>(self, other: T, t: impl Into<Ratio>) -> Self {
+        let other = other.to_rgba();
+        let t = t.into();
 
         RGBA {
-            r: (r_lhs * rgb_weight_lhs) + (r_rhs * rgb_weight_rhs),
-            g: (g_lhs * rgb_weight_lhs) + (g_rhs * rgb_weight_rhs),
-            b: (b_lhs * rgb_weight_lhs) + (b_rhs * rgb_weight_rhs),
-            a: (a_lhs * alpha_weight_lhs) + (a_rhs * alpha_weight_rhs),
+            r: self.r.lerp(other.r, t),
+            g: self.g.lerp(other.g, t),
+            b: self.b.lerp(other.b, t),
+            a: self.a.lerp(other.a, t),
         }
     }
 
-    fn tint(self, weight: Ratio) -> Self {
+    fn tint(self, weight: impl Into<Ratio>) -> Self {
         self.mix(rgb(255, 255, 255), weight)
     }
 
-    fn shade(self, weight: Ratio) -> Self {
+    fn shade(self, weight: impl Into<Ratio>) -> Self {
         self.mix(rgb(0, 0, 0), weight)
     }
 
@@ -419,6 +1266,31 @@ impl Color for RGBA {
         self.to_hsla().greyscale().to_rgba()
     }
 
+    fn invert_lightness(self) -> Self {
+        self.to_hsla().invert_lightness().to_rgba()
+    }
+
+    fn is_grayscale(self) -> bool {
+        self.r == self.g && self.g == self.b
+    }
+
+    fn map_rgb(self, f: impl Fn(Ratio) -> Ratio) -> Self {
+        RGBA {
+            r: f(self.r),
+            g: f(self.g),
+            b: f(self.b),
+            a: self.a,
+        }
+    }
+
+    fn warm(self, amount: Ratio) -> Self {
+        shift_towards(self, rgb(255, 160, 64), amount)
+    }
+
+    fn cool(self, amount: Ratio) -> Self {
+        shift_towards(self, rgb(64, 160, 255), amount)
+    }
+
     #[cfg(feature = "ansi_term")]
     fn ansi_paint<'a, I, S: 'a + ToOwned + ?Sized>(
         &self,
@@ -438,3 +1310,24 @@ impl From<RGBA> for (u8, u8, u8, f32) {
         (v.r.as_u8(), v.g.as_u8(), v.b.as_u8(), v.a.as_f32())
     }
 }
+
+/// The alpha byte is read as a fraction of 255, like the last byte of
+/// [`RGBA::from_u32`].
+impl From<[u8; 4]> for RGBA {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        RGBA {
+            r: Ratio::from_u8(r),
+            g: Ratio::from_u8(g),
+            b: Ratio::from_u8(b),
+            a: Ratio::from_u8(a),
+        }
+    }
+}
+
+/// The alpha byte is read as a fraction of 255, like the last byte of
+/// [`RGBA::from_u32`].
+impl From<(u8, u8, u8, u8)> for RGBA {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> Self {
+        RGBA::from([r, g, b, a])
+    }
+}
@@ -0,0 +1,106 @@
+// Conversions between RGB and the red, yellow and blue primaries of the painter's color
+// wheel, with every channel within `0.0-1.0`. See "Paint Inspired Color Mixing and
+// Compositing for Visualization" by Gossett and Chen, and the RGB and RYB conversions by
+// Sugita and Takahashi which approximate their model.
+
+// Converts normalized RGB channels into RYB channels.
+pub(crate) fn from_rgb([mut r, mut g, mut b]: [f32; 3]) -> [f32; 3] {
+    // Take out the white, to be added back at the end.
+    let white = r.min(g).min(b);
+    r -= white;
+    g -= white;
+    b -= white;
+
+    let max_rgb = r.max(g).max(b);
+
+    // Yellow is made of red and green light.
+    let mut y = r.min(g);
+    r -= y;
+    g -= y;
+
+    // Green is made of yellow and blue paint, each of which takes half of it.
+    if b > 0.0 && g > 0.0 {
+        b /= 2.0;
+        g /= 2.0;
+    }
+
+    y += g;
+    b += g;
+
+    // Keep the color as intense as it was.
+    let max_ryb = r.max(y).max(b);
+
+    if max_ryb > 0.0 {
+        let scale = max_rgb / max_ryb;
+        r *= scale;
+        y *= scale;
+        b *= scale;
+    }
+
+    [r + white, y + white, b + white]
+}
+
+// Converts RYB channels into normalized RGB channels, undoing `from_rgb`.
+pub(crate) fn to_rgb([mut r, mut y, mut b]: [f32; 3]) -> [f32; 3] {
+    let white = r.min(y).min(b);
+    r -= white;
+    y -= white;
+    b -= white;
+
+    let max_ryb = r.max(y).max(b);
+
+    // Green is made of yellow and blue paint.
+    let mut g = y.min(b);
+    y -= g;
+    b -= g;
+
+    if b > 0.0 && g > 0.0 {
+        b *= 2.0;
+        g *= 2.0;
+    }
+
+    // Yellow is made of red and green light.
+    r += y;
+    g += y;
+
+    let max_rgb = r.max(g).max(b);
+
+    if max_rgb > 0.0 {
+        let scale = max_ryb / max_rgb;
+        r *= scale;
+        g *= scale;
+        b *= scale;
+    }
+
+    [r + white, g + white, b + white]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_rgb, to_rgb};
+
+    #[test]
+    fn maps_primaries() {
+        assert_eq!(from_rgb([1.0, 0.0, 0.0]), [1.0, 0.0, 0.0]);
+        assert_eq!(from_rgb([1.0, 1.0, 0.0]), [0.0, 1.0, 0.0]);
+        assert_eq!(from_rgb([0.0, 0.0, 1.0]), [0.0, 0.0, 1.0]);
+        assert_eq!(to_rgb([0.0, 1.0, 1.0]), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn round_trips() {
+        for rgb in [
+            [0.0, 0.0, 0.0],
+            [1.0, 1.0, 1.0],
+            [0.2, 0.6, 0.4],
+            [0.9, 0.5, 0.1],
+            [0.3, 0.3, 0.8],
+        ] {
+            let round_trip = to_rgb(from_rgb(rgb));
+
+            for (lhs, rhs) in rgb.iter().zip(round_trip) {
+                assert!((lhs - rhs).abs() < 0.000_1, "{:?}", rgb);
+            }
+        }
+    }
+}